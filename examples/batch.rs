@@ -0,0 +1,38 @@
+use mistralai_client::v1::{
+    batch::BatchRequest,
+    chat::{ChatMessage, ChatRequest, ChatResponse},
+    client::Client,
+    constants::Model,
+};
+
+fn main() {
+    // This example suppose you have set the `MISTRAL_API_KEY` environment variable.
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let requests: Vec<BatchRequest<ChatRequest>> = ["Paris", "Lyon", "Marseille"]
+        .iter()
+        .map(|city| {
+            let messages = vec![ChatMessage::new_user_message(format!(
+                "Describe {} in one sentence.",
+                city
+            ))];
+            BatchRequest::new(
+                city,
+                ChatRequest::new(Model::OpenMistral7b, messages, false, None),
+            )
+        })
+        .collect();
+
+    let job = client.submit_batch(&requests, None).unwrap();
+    // The job ID can be saved to resume waiting for the job from another process.
+    let job = client.wait_batch_job(&job.id, None).unwrap();
+
+    let results = client.get_batch_results::<ChatResponse>(&job).unwrap();
+    for (custom_id, response) in results.responses {
+        println!("{}: {}", custom_id, response.choices[0].message.content);
+        // => "Paris: Paris is the capital of France, [...]"
+    }
+    for (custom_id, error) in results.errors {
+        eprintln!("{}: {}", custom_id, error.message);
+    }
+}
@@ -1,5 +1,5 @@
 use mistralai_client::v1::{
-    chat::{ChatMessage, ChatMessageRole, ChatParams},
+    chat::{ChatMessage, ChatParams},
     client::Client,
     constants::Model,
     tool::{Function, Tool, ToolChoice, ToolFunctionParameter, ToolFunctionParameterType},
@@ -47,13 +47,11 @@ fn main() {
     );
 
     let model = Model::MistralSmallLatest;
-    let messages = vec![ChatMessage {
-        role: ChatMessageRole::User,
-        content: "What's the temperature in Paris?".to_string(),
-        tool_calls: None,
-    }];
+    let messages = vec![ChatMessage::new_user_message(
+        "What's the temperature in Paris?",
+    )];
     let options = ChatParams {
-        temperature: 0.0,
+        temperature: Some(0.0),
         random_seed: Some(42),
         tool_choice: Some(ToolChoice::Auto),
         tools: Some(tools),
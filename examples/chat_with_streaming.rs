@@ -1,6 +1,6 @@
 use futures::stream::StreamExt;
 use mistralai_client::v1::{
-    chat::{ChatMessage, ChatMessageRole, ChatParams},
+    chat::{ChatMessage, ChatParams},
     client::Client,
     constants::Model,
 };
@@ -12,13 +12,11 @@ async fn main() {
     let client = Client::new(None, None, None, None).unwrap();
 
     let model = Model::OpenMistral7b;
-    let messages = vec![ChatMessage {
-        role: ChatMessageRole::User,
-        content: "Tell me a short happy story.".to_string(),
-        tool_calls: None,
-    }];
+    let messages = vec![ChatMessage::new_user_message(
+        "Tell me a short happy story.",
+    )];
     let options = ChatParams {
-        temperature: 0.0,
+        temperature: Some(0.0),
         random_seed: Some(42),
         ..Default::default()
     };
@@ -41,5 +39,5 @@ async fn main() {
             }
         })
         .await;
-    print!("\n") // To persist the last chunk output.
+    println!() // To persist the last chunk output.
 }
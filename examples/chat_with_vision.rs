@@ -0,0 +1,27 @@
+use mistralai_client::v1::{
+    chat::{ChatMessage, ChatMessageContentChunk, ChatParams},
+    client::Client,
+    constants::Model,
+};
+
+fn main() {
+    // This example suppose you have set the `MISTRAL_API_KEY` environment variable.
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let model = Model::MistralSmallLatest;
+    let messages = vec![ChatMessage::new_user_message(vec![
+        ChatMessageContentChunk::new_text("What's the name of this monument? Answer in one word."),
+        ChatMessageContentChunk::new_image_url(
+            "https://upload.wikimedia.org/wikipedia/commons/a/a8/Tour_Eiffel_Wikimedia_Commons.jpg",
+        ),
+    ])];
+    let options = ChatParams {
+        temperature: Some(0.0),
+        random_seed: Some(42),
+        ..Default::default()
+    };
+
+    let result = client.chat(model, messages, Some(options)).unwrap();
+    println!("Assistant: {}", result.choices[0].message.content);
+    // => "Assistant: Eiffel Tower."
+}
@@ -5,7 +5,7 @@ fn main() {
     let client: Client = Client::new(None, None, None, None).unwrap();
 
     let model = EmbedModel::MistralEmbed;
-    let input = vec!["Embed this sentence.", "As well as this one."]
+    let input = ["Embed this sentence.", "As well as this one."]
         .iter()
         .map(|s| s.to_string())
         .collect();
@@ -0,0 +1,19 @@
+use mistralai_client::v1::{client::Client, file::FilePurpose};
+
+fn main() {
+    // This example suppose you have set the `MISTRAL_API_KEY` environment variable.
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let file = client
+        .upload_file("training_file.jsonl", FilePurpose::FineTune)
+        .unwrap();
+    println!("Uploaded file: {} ({} bytes)", file.id, file.bytes);
+    // => "Uploaded file: 497f6eca-6276-4993-bfeb-53cbbbba6f09 (13000 bytes)"
+
+    let mut content = Vec::new();
+    client.download_file(&file.id, &mut content).unwrap();
+    println!("Downloaded content: {} bytes", content.len());
+    // => "Downloaded content: 13000 bytes"
+
+    client.delete_file(&file.id).unwrap();
+}
@@ -0,0 +1,22 @@
+use mistralai_client::v1::{client::Client, constants::Model, fim::FimParams};
+
+fn main() {
+    // This example suppose you have set the `MISTRAL_API_KEY` environment variable.
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let model = Model::CodestralLatest;
+    let prompt = "def fibonacci(n: int):".to_string();
+    let options = FimParams {
+        suffix: Some("\n\nprint(fibonacci(10))".to_string()),
+        temperature: Some(0.0),
+        random_seed: Some(42),
+        ..Default::default()
+    };
+
+    let result = client.fim(model, prompt, Some(options)).unwrap();
+    println!("Middle code: {}", result.choices[0].message.content);
+    // => "Middle code:
+    //     if n <= 1:
+    //         return n
+    //     return fibonacci(n - 1) + fibonacci(n - 2)"
+}
@@ -0,0 +1,45 @@
+use futures::stream::StreamExt;
+use mistralai_client::v1::{
+    client::Client,
+    constants::Model,
+    file::FilePurpose,
+    fine_tuning::{FineTuningHyperparameters, FineTuningJobParams, FineTuningTrainingFile},
+};
+
+#[tokio::main]
+async fn main() {
+    // This example suppose you have set the `MISTRAL_API_KEY` environment variable.
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let file = client
+        .upload_file_async("training_file.jsonl", FilePurpose::FineTune)
+        .await
+        .unwrap();
+
+    let options = FineTuningJobParams {
+        hyperparameters: Some(FineTuningHyperparameters {
+            training_steps: Some(10),
+            learning_rate: Some(0.0001),
+            ..Default::default()
+        }),
+        suffix: Some("support-bot".to_string()),
+        ..Default::default()
+    };
+    let job = client
+        .create_fine_tuning_job_async(
+            Model::OpenMistral7b,
+            vec![FineTuningTrainingFile::new(&file.id)],
+            Some(options),
+        )
+        .await
+        .unwrap();
+
+    let mut job_stream = Box::pin(client.watch_fine_tuning_job(&job.id, None));
+    while let Some(job_result) = job_stream.next().await {
+        match job_result {
+            Ok(job) => println!("Status: {:?}", job.status),
+            // => "Status: Queued", "Status: Running", [...] "Status: Success"
+            Err(error) => eprintln!("Error watching job: {:?}", error),
+        }
+    }
+}
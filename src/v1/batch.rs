@@ -0,0 +1,435 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{collections::HashMap, fmt, time::Duration};
+
+use crate::v1::{chat, constants, embedding, fim, utils};
+
+// -----------------------------------------------------------------------------
+// Definitions
+
+/// A request body which can be sent within a batch job.
+///
+/// It's implemented by [chat::ChatRequest], [embedding::EmbeddingRequest] and [fim::FimRequest].
+pub trait BatchRequestBody: Serialize {
+    fn endpoint() -> BatchEndpoint;
+
+    fn model_id(&self) -> &str;
+}
+impl BatchRequestBody for chat::ChatRequest {
+    fn endpoint() -> BatchEndpoint {
+        BatchEndpoint::ChatCompletions
+    }
+
+    fn model_id(&self) -> &str {
+        self.model.as_str()
+    }
+}
+impl BatchRequestBody for embedding::EmbeddingRequest {
+    fn endpoint() -> BatchEndpoint {
+        BatchEndpoint::Embeddings
+    }
+
+    fn model_id(&self) -> &str {
+        self.model.as_str()
+    }
+}
+impl BatchRequestBody for fim::FimRequest {
+    fn endpoint() -> BatchEndpoint {
+        BatchEndpoint::FimCompletions
+    }
+
+    fn model_id(&self) -> &str {
+        self.model.as_str()
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum BatchEndpoint {
+    #[serde(rename = "/v1/chat/completions")]
+    ChatCompletions,
+    #[serde(rename = "/v1/embeddings")]
+    Embeddings,
+    #[serde(rename = "/v1/fim/completions")]
+    FimCompletions,
+    #[serde(rename = "/v1/moderations")]
+    Moderations,
+    #[serde(rename = "/v1/chat/moderations")]
+    ChatModerations,
+    /// An endpoint unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl BatchEndpoint {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ChatCompletions => "/v1/chat/completions",
+            Self::Embeddings => "/v1/embeddings",
+            Self::FimCompletions => "/v1/fim/completions",
+            Self::Moderations => "/v1/moderations",
+            Self::ChatModerations => "/v1/chat/moderations",
+            Self::Unknown(endpoint) => endpoint,
+        }
+    }
+}
+impl fmt::Display for BatchEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// A request of a batch job input file, tagged with an ID to map it to its result.
+#[derive(Debug, Serialize)]
+pub struct BatchRequest<T: BatchRequestBody> {
+    /// A unique ID within the batch job, used as the key of [BatchResults].
+    pub custom_id: String,
+    pub body: T,
+}
+impl<T: BatchRequestBody> BatchRequest<T> {
+    pub fn new(custom_id: &str, body: T) -> Self {
+        Self {
+            custom_id: custom_id.to_string(),
+            body,
+        }
+    }
+}
+
+/// Returns the batch requests as a JSONL input file, one request per line.
+pub fn get_batch_input_jsonl<T: BatchRequestBody>(
+    requests: &[BatchRequest<T>],
+) -> Result<Vec<u8>, serde_json::Error> {
+    let mut content = Vec::new();
+    for request in requests {
+        serde_json::to_writer(&mut content, request)?;
+        content.push(b'\n');
+    }
+
+    Ok(content)
+}
+
+/// The parameters for the batch job creation request.
+///
+/// See the [API documentation](https://docs.mistral.ai/api/#tag/batch) for more information.
+#[derive(Clone, Debug, Default)]
+pub struct BatchJobParams {
+    /// Custom metadata attached to the job (e.g. to find it back when listing jobs).
+    ///
+    /// Defaults to `None`.
+    pub metadata: Option<HashMap<String, String>>,
+    /// The maximum duration of the job, after which it ends with a `TimeoutExceeded` status.
+    ///
+    /// Defaults to `None` (24 hours).
+    pub timeout_hours: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchJobRequest {
+    pub input_files: Vec<String>,
+    pub endpoint: BatchEndpoint,
+    pub model: constants::Model,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_hours: Option<u32>,
+}
+impl BatchJobRequest {
+    pub fn new(
+        input_files: Vec<String>,
+        endpoint: BatchEndpoint,
+        model: constants::Model,
+        options: Option<BatchJobParams>,
+    ) -> Self {
+        let BatchJobParams {
+            metadata,
+            timeout_hours,
+        } = options.unwrap_or_default();
+
+        Self {
+            input_files,
+            endpoint,
+            model,
+
+            metadata,
+            timeout_hours,
+        }
+    }
+}
+
+/// The parameters for the batch jobs list request.
+#[derive(Clone, Debug, Default)]
+pub struct BatchJobListParams {
+    /// Only list the jobs created after this date (ISO 8601).
+    ///
+    /// Defaults to `None`.
+    pub created_after: Option<String>,
+    /// Only list the jobs created by the API key owner.
+    ///
+    /// Defaults to `None`.
+    pub created_by_me: Option<bool>,
+    /// Only list the jobs using this model.
+    ///
+    /// Defaults to `None`.
+    pub model: Option<String>,
+    /// The page number, starting at `0`.
+    ///
+    /// Defaults to `None`.
+    pub page: Option<u32>,
+    /// The number of jobs per page.
+    ///
+    /// Defaults to `None`.
+    pub page_size: Option<u32>,
+    /// Only list the jobs with one of these statuses.
+    ///
+    /// Defaults to `None`.
+    pub status: Option<Vec<BatchJobStatus>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchJobListRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by_me: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<Vec<BatchJobStatus>>,
+}
+impl BatchJobListRequest {
+    pub fn new(options: Option<BatchJobListParams>) -> Self {
+        let BatchJobListParams {
+            created_after,
+            created_by_me,
+            model,
+            page,
+            page_size,
+            status,
+        } = options.unwrap_or_default();
+
+        Self {
+            created_after,
+            created_by_me,
+            model,
+            page,
+            page_size,
+            status,
+        }
+    }
+}
+
+/// The parameters of [Client::wait_batch_job](crate::v1::client::Client::wait_batch_job).
+#[derive(Clone, Debug)]
+pub struct BatchJobWaitParams {
+    /// The delay between the first two polls.
+    ///
+    /// Defaults to 5 seconds.
+    pub initial_interval: Duration,
+    /// The maximum delay between two polls.
+    ///
+    /// Defaults to 1 minute.
+    pub max_interval: Duration,
+    /// The factor the delay is multiplied by after each poll.
+    /// Values below `1.0` (or NaN) are treated as `1.0`.
+    ///
+    /// Defaults to `2.0`.
+    pub multiplier: f64,
+}
+impl BatchJobWaitParams {
+    /// Returns the delay following the `interval` one.
+    pub(crate) fn get_next_interval(&self, interval: Duration) -> Duration {
+        utils::get_next_backoff_interval(interval, self.multiplier, self.max_interval)
+    }
+}
+impl Default for BatchJobWaitParams {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            multiplier: 2.0,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchJobListResponse {
+    pub object: String,
+    pub data: Vec<BatchJobResponse>,
+    /// The total number of jobs matching the filters (across all the pages).
+    pub total: u32,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchJobResponse {
+    pub id: String,
+    pub object: String,
+    pub input_files: Vec<String>,
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+    pub endpoint: BatchEndpoint,
+    pub model: constants::Model,
+    /// The ID of the file containing the successful results, once the job is over.
+    pub output_file: Option<String>,
+    /// The ID of the file containing the failed results, once the job is over.
+    pub error_file: Option<String>,
+    /// The job-level errors (e.g. an invalid input file).
+    #[serde(default)]
+    pub errors: Vec<BatchJobError>,
+    pub status: BatchJobStatus,
+    /// Unix timestamp (in seconds).
+    pub created_at: u64,
+    pub total_requests: u32,
+    pub completed_requests: u32,
+    pub succeeded_requests: u32,
+    pub failed_requests: u32,
+    /// Unix timestamp (in seconds).
+    pub started_at: Option<u64>,
+    /// Unix timestamp (in seconds).
+    pub completed_at: Option<u64>,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchJobError {
+    pub message: String,
+    /// The number of requests failing with this error.
+    pub count: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum BatchJobStatus {
+    #[serde(rename = "QUEUED")]
+    Queued,
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "SUCCESS")]
+    Success,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(rename = "TIMEOUT_EXCEEDED")]
+    TimeoutExceeded,
+    #[serde(rename = "CANCELLATION_REQUESTED")]
+    CancellationRequested,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    /// A status unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl BatchJobStatus {
+    /// Returns whether the job is over and its status won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Success | Self::Failed | Self::TimeoutExceeded | Self::Cancelled
+        )
+    }
+}
+
+/// A line of a batch job output (or error) file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchOutputLine {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub custom_id: String,
+    pub response: Option<BatchOutputLineResponse>,
+    pub error: Option<serde_json::Value>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchOutputLineResponse {
+    pub status_code: u16,
+    pub body: serde_json::Value,
+}
+
+/// The results of a batch job, keyed by the `custom_id` of their request.
+#[derive(Clone, Debug)]
+pub struct BatchResults<T> {
+    pub responses: HashMap<String, T>,
+    pub errors: HashMap<String, BatchRequestError>,
+}
+impl<T: DeserializeOwned> BatchResults<T> {
+    /// Parses the content of batch job output and error files.
+    ///
+    /// Returns an error message if a line isn't a valid batch output line.
+    pub fn from_jsonl(contents: &[&str]) -> Result<Self, String> {
+        let mut results = Self {
+            responses: HashMap::new(),
+            errors: HashMap::new(),
+        };
+        for content in contents {
+            for (index, line) in content.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let output_line =
+                    serde_json::from_str::<BatchOutputLine>(line).map_err(|error| {
+                        format!("Invalid batch output line {}: {}", index + 1, error)
+                    })?;
+                results.add_output_line(output_line);
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn add_output_line(&mut self, output_line: BatchOutputLine) {
+        let BatchOutputLine {
+            custom_id,
+            response,
+            error,
+            ..
+        } = output_line;
+        let status_code = response.as_ref().map(|response| response.status_code);
+        let result = match (response, error) {
+            (Some(response), None) if (200..300).contains(&response.status_code) => {
+                serde_json::from_value::<T>(response.body).map_err(|error| error.to_string())
+            }
+            (Some(response), None) => Err(response.body.to_string()),
+            (_, Some(error)) => Err(error.to_string()),
+            (None, None) => Err("The request has neither a response nor an error.".to_string()),
+        };
+
+        match result {
+            Ok(data) => {
+                self.responses.insert(custom_id, data);
+            }
+            Err(message) => {
+                self.errors.insert(
+                    custom_id,
+                    BatchRequestError {
+                        status_code,
+                        message,
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// The error of a failed batch request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchRequestError {
+    /// The HTTP status code of the request, if it was sent.
+    pub status_code: Option<u16>,
+    /// The error message, or the raw error body.
+    pub message: String,
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, time::Duration};
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::v1::{batch, utils};
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters of [Client::run_batch_file_async](crate::v1::client::Client::run_batch_file_async).
+#[derive(Clone, Debug)]
+pub struct BatchRunnerParams {
+    /// The maximum number of requests sent at the same time.
+    ///
+    /// Defaults to `4`.
+    pub concurrency: usize,
+    /// The model of the requests without one, like the `model` of a batch job.
+    ///
+    /// Defaults to `None`.
+    pub model: Option<String>,
+    /// The maximum number of requests started per minute.
+    ///
+    /// Defaults to `None` (no rate limit).
+    pub requests_per_minute: Option<u32>,
+    /// The delay before retrying a request rejected by the API rate limit (`429` status code).
+    /// A request is retried up to [Client::max_retries](crate::v1::client::Client::max_retries) times.
+    ///
+    /// Defaults to 1 second.
+    pub rate_limit_initial_interval: Duration,
+    /// The maximum delay before retrying a rate-limited request.
+    ///
+    /// Defaults to 1 minute.
+    pub rate_limit_max_interval: Duration,
+    /// The factor the delay is multiplied by after each rate-limited retry.
+    /// Values below `1.0` (or NaN) are treated as `1.0`.
+    ///
+    /// Defaults to `2.0`.
+    pub rate_limit_multiplier: f64,
+}
+impl BatchRunnerParams {
+    /// Returns the rate-limited retry delay following the `interval` one.
+    pub(crate) fn get_next_rate_limit_interval(&self, interval: Duration) -> Duration {
+        utils::get_next_backoff_interval(
+            interval,
+            self.rate_limit_multiplier,
+            self.rate_limit_max_interval,
+        )
+    }
+}
+impl Default for BatchRunnerParams {
+    fn default() -> Self {
+        Self {
+            concurrency: 4,
+            model: None,
+            requests_per_minute: None,
+            rate_limit_initial_interval: Duration::from_secs(1),
+            rate_limit_max_interval: Duration::from_secs(60),
+            rate_limit_multiplier: 2.0,
+        }
+    }
+}
+
+/// A line of a batch job input file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchInputLine {
+    pub custom_id: String,
+    pub body: serde_json::Value,
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BatchRunReport {
+    /// The number of requests skipped because their result was already in the output file.
+    pub skipped_requests: u32,
+    /// The number of requests answered successfully.
+    pub succeeded_requests: u32,
+    /// The number of requests which failed (after the rate limit retries), including the ones
+    /// with an invalid body.
+    pub failed_requests: u32,
+}
+
+/// The results of a previous run found in its output file.
+#[derive(Clone, Debug, Default)]
+pub struct BatchOutputProgress {
+    /// The custom IDs of the requests with a result.
+    pub completed_custom_ids: HashSet<String>,
+    /// The length of the content made of complete lines, i.e. without a truncated last line.
+    pub valid_length: usize,
+    /// The numbers (starting at 1) of the complete lines which aren't batch output lines.
+    pub invalid_line_numbers: Vec<usize>,
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+/// Parses a batch job input file, checking that each `custom_id` is unique.
+pub fn parse_batch_input(content: &str) -> Result<Vec<BatchInputLine>, String> {
+    let mut custom_ids = HashSet::new();
+    let mut input_lines = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let input_line = serde_json::from_str::<BatchInputLine>(line)
+            .map_err(|error| format!("Invalid batch input line {}: {}", index + 1, error))?;
+        if !custom_ids.insert(input_line.custom_id.clone()) {
+            return Err(format!(
+                "Invalid batch input line {}: the `{}` custom ID is used twice.",
+                index + 1,
+                input_line.custom_id
+            ));
+        }
+        input_lines.push(input_line);
+    }
+
+    Ok(input_lines)
+}
+
+/// Returns the results of a previous run found in its output file.
+///
+/// A crash may leave a truncated last line (without a line break), which must be dropped
+/// before appending new lines. Any other invalid line is kept and reported, so that
+/// the results following it aren't lost.
+pub fn get_batch_output_progress(content: &str) -> BatchOutputProgress {
+    let mut progress = BatchOutputProgress::default();
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        if !line.ends_with('\n') {
+            break;
+        }
+        progress.valid_length += line.len();
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str::<batch::BatchOutputLine>(line) {
+            Ok(output_line) => {
+                progress.completed_custom_ids.insert(output_line.custom_id);
+            }
+            Err(_) => progress.invalid_line_numbers.push(index + 1),
+        }
+    }
+
+    progress
+}
+
+/// Spaces out the start of the requests to respect a requests-per-minute limit.
+pub(crate) struct RateLimiter {
+    interval: Option<Duration>,
+    next_start: Mutex<Instant>,
+}
+impl RateLimiter {
+    pub(crate) fn new(requests_per_minute: Option<u32>) -> Self {
+        Self {
+            interval: requests_per_minute
+                .filter(|requests_per_minute| *requests_per_minute > 0)
+                .map(|requests_per_minute| Duration::from_secs(60) / requests_per_minute),
+            next_start: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits until the next request can start.
+    pub(crate) async fn wait(&self) {
+        let interval = match self.interval {
+            Some(interval) => interval,
+            None => return,
+        };
+
+        let start = {
+            let mut next_start = self.next_start.lock().await;
+            let start = (*next_start).max(Instant::now());
+            *next_start = start + interval;
+
+            start
+        };
+        tokio::time::sleep_until(start).await;
+    }
+}
@@ -1,6 +1,12 @@
-use serde::{Deserialize, Serialize};
+use base64::Engine;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use std::{collections::HashMap, fmt, fs, path::Path};
 
-use crate::v1::{common, constants, tool};
+use crate::v1::{common, constants, error, tool};
+
+/// Maximum size of an image sent as a base64 data URI.
+pub const MAX_IMAGE_SIZE_BYTES: usize = 10 * 1024 * 1024;
 
 // -----------------------------------------------------------------------------
 // Definitions
@@ -8,30 +14,287 @@ use crate::v1::{common, constants, tool};
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChatMessage {
     pub role: ChatMessageRole,
-    pub content: String,
+    #[serde(default, deserialize_with = "deserialize_nullable_content")]
+    pub content: ChatMessageContent,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_calls: Option<Vec<tool::ToolCall>>,
+    /// The ID of the tool call this message is the result of (`Tool` messages only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+    /// The name of the function this message is the result of (`Tool` messages only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether the model must continue this message instead of answering it (`Assistant` messages only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<bool>,
 }
 impl ChatMessage {
-    pub fn new_assistant_message(content: &str, tool_calls: Option<Vec<tool::ToolCall>>) -> Self {
+    pub fn new_system_message(content: impl Into<ChatMessageContent>) -> Self {
+        Self {
+            role: ChatMessageRole::System,
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: None,
+            name: None,
+            prefix: None,
+        }
+    }
+
+    pub fn new_assistant_message(
+        content: impl Into<ChatMessageContent>,
+        tool_calls: Option<Vec<tool::ToolCall>>,
+    ) -> Self {
         Self {
             role: ChatMessageRole::Assistant,
-            content: content.to_string(),
+            content: content.into(),
             tool_calls,
+            tool_call_id: None,
+            name: None,
+            prefix: None,
         }
     }
 
-    pub fn new_user_message(content: &str) -> Self {
+    /// Constructs an assistant message the model will continue from, e.g. to enforce a format.
+    pub fn new_assistant_prefix_message(content: impl Into<ChatMessageContent>) -> Self {
+        Self {
+            role: ChatMessageRole::Assistant,
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: None,
+            name: None,
+            prefix: Some(true),
+        }
+    }
+
+    pub fn new_user_message(content: impl Into<ChatMessageContent>) -> Self {
         Self {
             role: ChatMessageRole::User,
-            content: content.to_string(),
+            content: content.into(),
+            tool_calls: None,
+            tool_call_id: None,
+            name: None,
+            prefix: None,
+        }
+    }
+
+    /// Constructs the message holding the result of a tool call, linked to it by `tool_call_id`.
+    pub fn new_tool_message(
+        content: impl Into<ChatMessageContent>,
+        tool_call_id: &str,
+        name: Option<&str>,
+    ) -> Self {
+        Self {
+            role: ChatMessageRole::Tool,
+            content: content.into(),
             tool_calls: None,
+            tool_call_id: Some(tool_call_id.to_string()),
+            name: name.map(|name| name.to_string()),
+            prefix: None,
+        }
+    }
+
+    /// Returns a copy of the message without its thinking chunks.
+    ///
+    /// Useful to append a reasoning model answer to the conversation history without its reasoning.
+    pub fn without_thinking(&self) -> Self {
+        Self {
+            content: self.content.without_thinking(),
+            ..self.clone()
+        }
+    }
+}
+
+/// The content of a chat message, either a plain text or a list of typed chunks.
+///
+/// See the [Mistral AI API documentation](https://docs.mistral.ai/capabilities/vision/) for more information.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ChatMessageContent {
+    Text(String),
+    Chunks(Vec<ChatMessageContentChunk>),
+}
+impl ChatMessageContent {
+    /// Returns the text of the content, concatenating all its text chunks.
+    ///
+    /// Thinking chunks are excluded, so this is the final answer of reasoning models.
+    pub fn text(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Chunks(chunks) => get_chunks_text(chunks),
+        }
+    }
+
+    /// Returns the reasoning text of the content, concatenating all its thinking chunks.
+    pub fn thinking(&self) -> String {
+        match self {
+            Self::Text(_) => String::new(),
+            Self::Chunks(chunks) => chunks
+                .iter()
+                .filter_map(|chunk| match chunk {
+                    ChatMessageContentChunk::Thinking { thinking } => {
+                        Some(get_chunks_text(thinking))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of the content without its thinking chunks.
+    pub fn without_thinking(&self) -> Self {
+        match self {
+            Self::Text(text) => Self::Text(text.clone()),
+            Self::Chunks(chunks) => Self::Chunks(
+                chunks
+                    .iter()
+                    .filter(|chunk| !matches!(chunk, ChatMessageContentChunk::Thinking { .. }))
+                    .cloned()
+                    .collect(),
+            ),
+        }
+    }
+}
+impl Default for ChatMessageContent {
+    fn default() -> Self {
+        Self::Text(String::new())
+    }
+}
+impl fmt::Display for ChatMessageContent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+impl From<&str> for ChatMessageContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+impl From<String> for ChatMessageContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+impl From<Vec<ChatMessageContentChunk>> for ChatMessageContent {
+    fn from(chunks: Vec<ChatMessageContentChunk>) -> Self {
+        Self::Chunks(chunks)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type")]
+#[non_exhaustive]
+pub enum ChatMessageContentChunk {
+    #[serde(rename = "text")]
+    Text { text: String },
+    /// An image, either as a public URL or as a base64 data URI.
+    #[serde(rename = "image_url")]
+    ImageUrl {
+        #[serde(deserialize_with = "deserialize_image_url")]
+        image_url: String,
+    },
+    /// A document (e.g. a PDF) available at a public URL.
+    #[serde(rename = "document_url")]
+    DocumentUrl {
+        document_url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        document_name: Option<String>,
+    },
+    /// A document previously uploaded through the Files API.
+    #[serde(rename = "file")]
+    File { file_id: String },
+    /// The reasoning of a reasoning model, preceding its final answer.
+    #[serde(rename = "thinking")]
+    Thinking {
+        thinking: Vec<ChatMessageContentChunk>,
+    },
+    /// A chunk type unknown to this client version, kept as is.
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+impl ChatMessageContentChunk {
+    pub fn new_text(text: &str) -> Self {
+        Self::Text {
+            text: text.to_string(),
+        }
+    }
+
+    /// Constructs an image chunk from either a public URL or a base64 data URI.
+    pub fn new_image_url(url: &str) -> Self {
+        Self::ImageUrl {
+            image_url: url.to_string(),
+        }
+    }
+
+    /// Constructs a document chunk from a public URL, with an optional display name.
+    pub fn new_document_url(url: &str, document_name: Option<&str>) -> Self {
+        Self::DocumentUrl {
+            document_url: url.to_string(),
+            document_name: document_name.map(|name| name.to_string()),
+        }
+    }
+
+    /// Constructs a document chunk referencing an uploaded file by its ID.
+    pub fn new_file(file_id: &str) -> Self {
+        Self::File {
+            file_id: file_id.to_string(),
+        }
+    }
+
+    /// Constructs an image chunk embedding the image bytes as a base64 data URI.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the image is larger than [MAX_IMAGE_SIZE_BYTES].
+    pub fn new_image_from_bytes(bytes: &[u8], mime_type: &str) -> Result<Self, error::ImageError> {
+        if bytes.len() > MAX_IMAGE_SIZE_BYTES {
+            return Err(error::ImageError::TooLarge {
+                size: bytes.len(),
+                max_size: MAX_IMAGE_SIZE_BYTES,
+            });
+        }
+
+        let data = base64::engine::general_purpose::STANDARD.encode(bytes);
+
+        Ok(Self::new_image_url(&format!(
+            "data:{};base64,{}",
+            mime_type, data
+        )))
+    }
+
+    /// Constructs an image chunk from a local image file, detecting its MIME type.
+    ///
+    /// Supported formats are PNG, JPEG, WEBP and GIF.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the file can't be read, isn't a supported image
+    /// or is larger than [MAX_IMAGE_SIZE_BYTES].
+    pub fn new_image_from_file<P: AsRef<Path>>(path: P) -> Result<Self, error::ImageError> {
+        let path = path.as_ref();
+        let size = fs::metadata(path)
+            .map_err(|error| error::ImageError::UnreadableFile(error.to_string()))?
+            .len() as usize;
+        // Fail early to avoid loading huge files into memory.
+        if size > MAX_IMAGE_SIZE_BYTES {
+            return Err(error::ImageError::TooLarge {
+                size,
+                max_size: MAX_IMAGE_SIZE_BYTES,
+            });
         }
+
+        let bytes =
+            fs::read(path).map_err(|error| error::ImageError::UnreadableFile(error.to_string()))?;
+        let mime_type = detect_image_mime_type(&bytes, path).ok_or_else(|| {
+            error::ImageError::UnsupportedFormat(path.to_string_lossy().to_string())
+        })?;
+
+        Self::new_image_from_bytes(&bytes, mime_type)
     }
 }
 
 /// See the [Mistral AI API documentation](https://docs.mistral.ai/capabilities/completion/#chat-messages) for more information.
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
 pub enum ChatMessageRole {
     #[serde(rename = "system")]
     System,
@@ -41,6 +304,19 @@ pub enum ChatMessageRole {
     User,
     #[serde(rename = "tool")]
     Tool,
+    /// A role unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The system prompt mode of reasoning models.
+///
+/// See the [API documentation](https://docs.mistral.ai/capabilities/reasoning/) for more information.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum ChatPromptMode {
+    /// Use the default reasoning system prompt.
+    #[serde(rename = "reasoning")]
+    Reasoning,
 }
 
 /// The format that the model must output.
@@ -50,27 +326,116 @@ pub enum ChatMessageRole {
 pub struct ResponseFormat {
     #[serde(rename = "type")]
     pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_schema: Option<ResponseFormatJsonSchema>,
 }
 impl ResponseFormat {
+    pub fn text() -> Self {
+        Self {
+            type_: "text".to_string(),
+            json_schema: None,
+        }
+    }
+
     pub fn json_object() -> Self {
         Self {
             type_: "json_object".to_string(),
+            json_schema: None,
+        }
+    }
+
+    /// Constrains the model output to the given JSON Schema.
+    pub fn json_schema(name: &str, schema: serde_json::Value, strict: bool) -> Self {
+        Self {
+            type_: "json_schema".to_string(),
+            json_schema: Some(ResponseFormatJsonSchema {
+                name: name.to_string(),
+                description: None,
+                schema,
+                strict,
+            }),
+        }
+    }
+
+    /// Constrains the model output to the JSON Schema derived from the `T` Rust type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mistralai_client::v1::chat::ResponseFormat;
+    /// use schemars::JsonSchema;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize, JsonSchema)]
+    /// struct City {
+    ///     name: String,
+    ///     population: u32,
+    /// }
+    ///
+    /// let response_format = ResponseFormat::json_schema_for::<City>(true);
+    /// assert_eq!(response_format.json_schema.unwrap().name, "City");
+    /// ```
+    pub fn json_schema_for<T: JsonSchema>(strict: bool) -> Self {
+        let mut schema = serde_json::to_value(schemars::schema_for!(T)).unwrap_or_default();
+        if let Some(schema) = schema.as_object_mut() {
+            schema.remove("$schema");
         }
+
+        Self::json_schema(&T::schema_name(), schema, strict)
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResponseFormatJsonSchema {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub schema: serde_json::Value,
+    /// Whether the model output must strictly follow the schema.
+    pub strict: bool,
+}
+
 // -----------------------------------------------------------------------------
 // Request
 
 /// The parameters for the chat request.
 ///
 /// See the [API documentation](https://docs.mistral.ai/api/#operation/createChatCompletion) for more information.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct ChatParams {
+    /// Penalizes the repetition of tokens according to their frequency in the generated text.
+    ///
+    /// Defaults to `None`.
+    pub frequency_penalty: Option<f32>,
     /// The maximum number of tokens to generate in the completion.
     ///
     /// Defaults to `None`.
     pub max_tokens: Option<u32>,
+    /// The minimum number of tokens to generate in the completion.
+    ///
+    /// Defaults to `None`.
+    pub min_tokens: Option<u32>,
+    /// The number of completions to return for each request (as many response choices).
+    ///
+    /// Defaults to `None`.
+    pub n: Option<u32>,
+    /// Whether the model can call multiple functions in a single response.
+    ///
+    /// Defaults to `None`.
+    pub parallel_tool_calls: Option<bool>,
+    /// The expected completion, used to speed up the generation when most of it is known in advance
+    /// (e.g. code edits).
+    ///
+    /// Defaults to `None`.
+    pub prediction: Option<ChatPrediction>,
+    /// Penalizes the repetition of tokens that already appear in the generated text.
+    ///
+    /// Defaults to `None`.
+    pub presence_penalty: Option<f32>,
+    /// The system prompt mode of reasoning models. Set it to `None` to use your own system prompt.
+    ///
+    /// Defaults to `None`.
+    pub prompt_mode: Option<ChatPromptMode>,
     /// The seed to use for random sampling. If set, different calls will generate deterministic results.
     ///
     /// Defaults to `None`.
@@ -83,10 +448,15 @@ pub struct ChatParams {
     ///
     /// Defaults to `false`.
     pub safe_prompt: bool,
+    /// The sequences that stop the generation when they are generated.
+    ///
+    /// Defaults to `None`.
+    pub stop: Option<Vec<String>>,
     /// What sampling temperature to use, between `Some(0.0)` and `Some(1.0)`.
+    /// The model default temperature is used when `None`.
     ///
-    /// Defaults to `0.7`.
-    pub temperature: f32,
+    /// Defaults to `None`.
+    pub temperature: Option<f32>,
     /// Specifies if/how functions are called.
     ///
     /// Defaults to `None`.
@@ -96,58 +466,83 @@ pub struct ChatParams {
     /// Defaults to `None`.
     pub tools: Option<Vec<tool::Tool>>,
     /// Nucleus sampling, where the model considers the results of the tokens with `top_p` probability mass.
+    /// The model default is used when `None`.
     ///
-    /// Defaults to `1.0`.
-    pub top_p: f32,
+    /// Defaults to `None`.
+    pub top_p: Option<f32>,
 }
-impl Default for ChatParams {
-    fn default() -> Self {
+impl ChatParams {
+    /// Returns the default parameters with the [ResponseFormat::json_object] response format.
+    pub fn json_default() -> Self {
         Self {
-            max_tokens: None,
-            random_seed: None,
-            safe_prompt: false,
-            response_format: None,
-            temperature: 0.7,
-            tool_choice: None,
-            tools: None,
-            top_p: 1.0,
+            response_format: Some(ResponseFormat::json_object()),
+            ..Default::default()
         }
     }
 }
-impl ChatParams {
-    pub fn json_default() -> Self {
+
+/// The expected completion of a chat request.
+///
+/// See the [API documentation](https://docs.mistral.ai/capabilities/predicted_outputs/) for more information.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ChatPrediction {
+    pub r#type: ChatPredictionType,
+    pub content: String,
+}
+impl ChatPrediction {
+    pub fn new(content: &str) -> Self {
         Self {
-            max_tokens: None,
-            random_seed: None,
-            safe_prompt: false,
-            response_format: None,
-            temperature: 0.7,
-            tool_choice: None,
-            tools: None,
-            top_p: 1.0,
+            r#type: ChatPredictionType::Content,
+            content: content.to_string(),
         }
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum ChatPredictionType {
+    #[serde(rename = "content")]
+    Content,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ChatRequest {
     pub messages: Vec<ChatMessage>,
     pub model: constants::Model,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prediction: Option<ChatPrediction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_mode: Option<ChatPromptMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub random_seed: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub response_format: Option<ResponseFormat>,
+    #[serde(default)]
     pub safe_prompt: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    #[serde(default)]
     pub stream: bool,
-    pub temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_choice: Option<tool::ToolChoice>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<tool::Tool>>,
-    pub top_p: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
 }
 impl ChatRequest {
     pub fn new(
@@ -157,29 +552,45 @@ impl ChatRequest {
         options: Option<ChatParams>,
     ) -> Self {
         let ChatParams {
+            frequency_penalty,
             max_tokens,
+            min_tokens,
+            n,
+            parallel_tool_calls,
+            prediction,
+            presence_penalty,
+            prompt_mode,
             random_seed,
+            response_format,
             safe_prompt,
+            stop,
             temperature,
             tool_choice,
             tools,
             top_p,
-            response_format,
         } = options.unwrap_or_default();
 
         Self {
             messages,
             model,
 
+            frequency_penalty,
             max_tokens,
+            min_tokens,
+            n,
+            parallel_tool_calls,
+            prediction,
+            presence_penalty,
+            prompt_mode,
             random_seed,
+            response_format,
             safe_prompt,
+            stop,
             stream,
             temperature,
             tool_choice,
             tools,
             top_p,
-            response_format,
         }
     }
 }
@@ -196,6 +607,43 @@ pub struct ChatResponse {
     pub model: constants::Model,
     pub choices: Vec<ChatResponseChoice>,
     pub usage: common::ResponseUsage,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl ChatResponse {
+    /// Deserializes the first choice assistant message content into `T`.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the response has no choice or its content isn't a valid `T`,
+    /// in which case the error carries the raw content.
+    pub fn parse_content<T: DeserializeOwned>(&self) -> Result<T, error::ChatParseError> {
+        let choice = self
+            .choices
+            .first()
+            .ok_or(error::ChatParseError::MissingChoice)?;
+
+        choice.parse_content()
+    }
+
+    /// Deserializes the assistant message content of every choice into `T`, in the choices order.
+    ///
+    /// This is useful when requesting multiple choices via [ChatParams::n].
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the response has no choice or any content isn't a valid `T`.
+    pub fn parse_contents<T: DeserializeOwned>(&self) -> Result<Vec<T>, error::ChatParseError> {
+        if self.choices.is_empty() {
+            return Err(error::ChatParseError::MissingChoice);
+        }
+
+        self.choices
+            .iter()
+            .map(|choice| choice.parse_content())
+            .collect()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -205,12 +653,105 @@ pub struct ChatResponseChoice {
     pub finish_reason: ChatResponseChoiceFinishReason,
     // TODO Check this prop (seen in API responses but undocumented).
     // pub logprobs: ???
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl ChatResponseChoice {
+    /// Deserializes the assistant message content into `T`.
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the content isn't a valid `T`, in which case the error carries
+    /// the raw content.
+    pub fn parse_content<T: DeserializeOwned>(&self) -> Result<T, error::ChatParseError> {
+        let raw_content = self.message.content.text();
+
+        serde_json::from_str::<T>(&raw_content).map_err(|error| {
+            error::ChatParseError::InvalidContent {
+                message: error.to_string(),
+                raw_content,
+            }
+        })
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
 pub enum ChatResponseChoiceFinishReason {
     #[serde(rename = "stop")]
     Stop,
+    /// The completion reached the requested `max_tokens`.
+    #[serde(rename = "length")]
+    Length,
+    /// The completion reached the model context length.
+    #[serde(rename = "model_length")]
+    ModelLength,
+    /// The generation failed.
+    #[serde(rename = "error")]
+    Error,
     #[serde(rename = "tool_calls")]
     ToolCalls,
+    /// A finish reason unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+/// Treats a `null` content (e.g. in assistant messages with tool calls) as an empty text.
+pub(crate) fn deserialize_nullable_content<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ChatMessageContent, D::Error> {
+    Ok(Option::<ChatMessageContent>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+fn get_chunks_text(chunks: &[ChatMessageContentChunk]) -> String {
+    chunks
+        .iter()
+        .filter_map(|chunk| match chunk {
+            ChatMessageContentChunk::Text { text } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Accepts both the `"image_url": "<url>"` and `"image_url": { "url": "<url>" }` shapes.
+fn deserialize_image_url<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ImageUrl {
+        Url(String),
+        Object { url: String },
+    }
+
+    match ImageUrl::deserialize(deserializer)? {
+        ImageUrl::Url(url) | ImageUrl::Object { url } => Ok(url),
+    }
+}
+
+/// Detects the image MIME type from its magic bytes, falling back to the file extension.
+fn detect_image_mime_type(bytes: &[u8], path: &Path) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return Some("image/png");
+    }
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some("image/jpeg");
+    }
+    if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        return Some("image/gif");
+    }
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    match extension.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
 }
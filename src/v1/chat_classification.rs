@@ -0,0 +1,234 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::v1::{chat, error, tool};
+
+/// The name of the function the model is forced to call to classify the input.
+pub const CLASSIFICATION_FUNCTION_NAME: &str = "classify";
+
+// -----------------------------------------------------------------------------
+// Definitions
+
+/// A fixed set of labels the model must pick one from, usually implemented by an enum
+/// via the [crate::impl_chat_classification] macro.
+pub trait ChatClassification: Sized {
+    /// Returns the label and description of every variant.
+    fn labels() -> Vec<(&'static str, &'static str)>;
+
+    /// Returns the variant matching the `label`, if any.
+    fn from_label(label: &str) -> Option<Self>;
+}
+
+/// Implements [ChatClassification] for an enum, listing each variant label and description.
+///
+/// # Examples
+///
+/// ```
+/// use mistralai_client::impl_chat_classification;
+/// use mistralai_client::v1::chat_classification::ChatClassification;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Sentiment {
+///     Positive,
+///     Negative,
+/// }
+/// impl_chat_classification!(Sentiment {
+///     Positive => ("positive", "The text expresses a positive opinion."),
+///     Negative => ("negative", "The text expresses a negative opinion."),
+/// });
+///
+/// assert_eq!(Sentiment::from_label("negative"), Some(Sentiment::Negative));
+/// ```
+#[macro_export]
+macro_rules! impl_chat_classification {
+    ($type:ident { $($variant:ident => ($label:expr, $description:expr)),+ $(,)? }) => {
+        impl $crate::v1::chat_classification::ChatClassification for $type {
+            fn labels() -> Vec<(&'static str, &'static str)> {
+                vec![$(($label, $description)),+]
+            }
+
+            fn from_label(label: &str) -> Option<Self> {
+                $(
+                    if label == $label {
+                        return Some($type::$variant);
+                    }
+                )+
+
+                None
+            }
+        }
+    };
+}
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters for the chat classification request.
+#[derive(Clone, Debug, Default)]
+pub struct ChatClassificationParams {
+    /// Instructions sent as a system message before the input.
+    ///
+    /// Defaults to `None`.
+    pub instructions: Option<String>,
+    /// Whether the model must also return its confidence, between `0.0` and `1.0`.
+    ///
+    /// Defaults to `false`.
+    pub with_confidence: bool,
+    /// Whether the model must also explain its choice.
+    ///
+    /// Defaults to `false`.
+    pub with_rationale: bool,
+    /// The chat parameters. Their `tools` and `tool_choice` are overridden.
+    ///
+    /// Defaults to [chat::ChatParams::default].
+    pub chat_params: chat::ChatParams,
+}
+
+/// Returns the tool the model is forced to call, with an `enum` parameter listing the labels of `T`.
+pub fn get_classification_tool<T: ChatClassification>(
+    params: &ChatClassificationParams,
+) -> tool::Tool {
+    let labels = T::labels();
+    let label_description = labels.iter().fold(
+        "The label of the input. One of:".to_string(),
+        |description, (label, label_description)| {
+            format!("{}\n- `{}`: {}", description, label, label_description)
+        },
+    );
+
+    let mut properties = serde_json::Map::new();
+    let mut required = vec!["label"];
+    properties.insert(
+        "label".to_string(),
+        json!({
+            "type": "string",
+            "enum": labels.iter().map(|(label, _)| label).collect::<Vec<_>>(),
+            "description": label_description,
+        }),
+    );
+    if params.with_confidence {
+        properties.insert(
+            "confidence".to_string(),
+            json!({
+                "type": "number",
+                "minimum": 0.0,
+                "maximum": 1.0,
+                "description": "How confident you are in the label, between 0 and 1.",
+            }),
+        );
+        required.push("confidence");
+    }
+    if params.with_rationale {
+        properties.insert(
+            "rationale".to_string(),
+            json!({
+                "type": "string",
+                "description": "A short explanation of why this label was chosen.",
+            }),
+        );
+        required.push("rationale");
+    }
+
+    tool::Tool::new_with_schema(
+        CLASSIFICATION_FUNCTION_NAME.to_string(),
+        "Classify the input with a single label.".to_string(),
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        }),
+    )
+}
+
+/// Returns the chat messages and parameters of the classification request.
+pub(crate) fn get_classification_request<T: ChatClassification>(
+    input: &str,
+    options: Option<ChatClassificationParams>,
+) -> (Vec<chat::ChatMessage>, chat::ChatParams) {
+    let params = options.unwrap_or_default();
+
+    let mut messages = Vec::new();
+    if let Some(instructions) = &params.instructions {
+        messages.push(chat::ChatMessage::new_system_message(instructions.as_str()));
+    }
+    messages.push(chat::ChatMessage::new_user_message(input));
+
+    let chat_params = chat::ChatParams {
+        tools: Some(vec![get_classification_tool::<T>(&params)]),
+        tool_choice: Some(tool::ToolChoice::new_function(CLASSIFICATION_FUNCTION_NAME)),
+        ..params.chat_params
+    };
+
+    (messages, chat_params)
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug)]
+pub struct ChatClassificationResult<T> {
+    pub label: T,
+    /// The model confidence, if [ChatClassificationParams] `with_confidence` was set.
+    pub confidence: Option<f32>,
+    /// The model explanation, if [ChatClassificationParams] `with_rationale` was set.
+    pub rationale: Option<String>,
+    pub response: chat::ChatResponse,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatClassificationArguments {
+    label: String,
+    confidence: Option<f32>,
+    rationale: Option<String>,
+}
+
+/// Parses the classification function call of the response (of its first choice calling it).
+///
+/// # Errors
+///
+/// This method fails whenever the response has no choice, or its function call is missing,
+/// malformed or has an unknown label, in which case the error carries the raw arguments.
+pub fn parse_classification_response<T: ChatClassification>(
+    response: chat::ChatResponse,
+) -> Result<ChatClassificationResult<T>, error::ChatParseError> {
+    let first_choice = response
+        .choices
+        .first()
+        .ok_or(error::ChatParseError::MissingChoice)?;
+    // With multiple choices, the first one calling the classification function wins.
+    let tool_call = response
+        .choices
+        .iter()
+        .find_map(|choice| {
+            choice.message.tool_calls.as_ref().and_then(|tool_calls| {
+                tool_calls
+                    .iter()
+                    .find(|tool_call| tool_call.function.name == CLASSIFICATION_FUNCTION_NAME)
+            })
+        })
+        .ok_or_else(|| error::ChatParseError::InvalidContent {
+            message: format!("missing `{}` function call", CLASSIFICATION_FUNCTION_NAME),
+            raw_content: first_choice.message.content.text(),
+        })?;
+
+    let raw_arguments = tool_call.function.arguments.clone();
+    let arguments =
+        serde_json::from_str::<ChatClassificationArguments>(&raw_arguments).map_err(|error| {
+            error::ChatParseError::InvalidContent {
+                message: error.to_string(),
+                raw_content: raw_arguments.clone(),
+            }
+        })?;
+    let label =
+        T::from_label(&arguments.label).ok_or_else(|| error::ChatParseError::InvalidContent {
+            message: format!("unknown label `{}`", arguments.label),
+            raw_content: raw_arguments.clone(),
+        })?;
+
+    Ok(ChatClassificationResult {
+        label,
+        confidence: arguments.confidence,
+        rationale: arguments.rationale,
+        response,
+    })
+}
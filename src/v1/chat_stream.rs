@@ -1,5 +1,7 @@
+use futures::{stream::StreamExt, Stream};
 use serde::{Deserialize, Serialize};
 use serde_json::from_str;
+use std::collections::HashMap;
 
 use crate::v1::{chat, common, constants, error};
 
@@ -17,6 +19,9 @@ pub struct ChatStreamChunk {
     pub usage: Option<common::ResponseUsage>,
     // TODO Check this prop (seen in API responses but undocumented).
     // pub logprobs: ???,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -26,12 +31,17 @@ pub struct ChatStreamChunkChoice {
     pub finish_reason: Option<String>,
     // TODO Check this prop (seen in API responses but undocumented).
     // pub logprobs: ???,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChatStreamChunkChoiceDelta {
     pub role: Option<chat::ChatMessageRole>,
-    pub content: String,
+    /// The content delta, including the thinking chunks of reasoning models.
+    #[serde(default, deserialize_with = "chat::deserialize_nullable_content")]
+    pub content: chat::ChatMessageContent,
 }
 
 /// Extracts serialized chunks from a stream message.
@@ -52,6 +62,37 @@ pub fn get_chunk_from_stream_message_line(
         Ok(chunk) => Ok(Some(vec![chunk])),
         Err(e) => Err(error::ApiError {
             message: e.to_string(),
+            kind: error::ApiErrorKind::Serialization,
         }),
     }
 }
+
+/// Deserializes a streamed (server-sent events) response into chunks.
+pub(crate) fn deserialize_stream_response(
+    response: reqwest::Response,
+) -> impl Stream<Item = Result<Vec<ChatStreamChunk>, error::ApiError>> {
+    response.bytes_stream().then(|bytes_result| async move {
+        match bytes_result {
+            Ok(bytes) => match String::from_utf8(bytes.to_vec()) {
+                Ok(message) => {
+                    let chunks = message
+                        .lines()
+                        .filter_map(|line| match get_chunk_from_stream_message_line(line) {
+                            Ok(Some(chunks)) => Some(chunks),
+                            Ok(None) => None,
+                            Err(_error) => None,
+                        })
+                        .flatten()
+                        .collect();
+
+                    Ok(chunks)
+                }
+                Err(e) => Err(error::ApiError {
+                    message: e.to_string(),
+                    kind: error::ApiErrorKind::Serialization,
+                }),
+            },
+            Err(e) => Err(error::ApiError::from_reqwest_error(e)),
+        }
+    })
+}
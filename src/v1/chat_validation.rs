@@ -0,0 +1,197 @@
+use log::debug;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+use crate::v1::{chat, common, error};
+
+/// The corrective user message appended after an invalid assistant message.
+///
+/// `{error}` is replaced with the validation error.
+pub const CORRECTION_PROMPT: &str =
+    "Your previous answer is invalid: {error}\nFix it and answer again with the corrected output only.";
+
+// -----------------------------------------------------------------------------
+// Definitions
+
+/// Validates and converts the assistant message content into `T`.
+///
+/// It's implemented for any `Fn(&str) -> Result<T, String>` closure,
+/// and by [SerdeValidator] to deserialize the content as JSON.
+pub trait ChatValidator<T> {
+    /// Returns the validated value, or an error message sent back to the model.
+    fn validate(&self, content: &str) -> Result<T, String>;
+}
+impl<T, F: Fn(&str) -> Result<T, String>> ChatValidator<T> for F {
+    fn validate(&self, content: &str) -> Result<T, String> {
+        self(content)
+    }
+}
+
+/// A [ChatValidator] deserializing the assistant message content as a JSON `T`.
+#[derive(Debug)]
+pub struct SerdeValidator<T> {
+    _marker: PhantomData<T>,
+}
+impl<T> SerdeValidator<T> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+impl<T> Default for SerdeValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: DeserializeOwned> ChatValidator<T> for SerdeValidator<T> {
+    fn validate(&self, content: &str) -> Result<T, String> {
+        serde_json::from_str::<T>(content).map_err(|error| error.to_string())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug)]
+pub struct ChatValidationResult<T> {
+    /// The validated value.
+    pub value: T,
+    /// All the attempts, the last one being the valid one.
+    pub attempts: Vec<ChatValidationAttempt>,
+    /// The usage of all the attempts combined.
+    pub usage: common::ResponseUsage,
+}
+
+#[derive(Clone, Debug)]
+pub struct ChatValidationAttempt {
+    pub response: chat::ChatResponse,
+    /// The validation error, if the assistant message content was invalid.
+    pub error: Option<String>,
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+/// The outcome of a validated chat attempt.
+pub(crate) enum ChatValidationStep<T> {
+    /// The validation is over, either with a valid value or an error.
+    Done(Result<ChatValidationResult<T>, error::ChatValidationError>),
+    /// The assistant message is invalid: these messages must be appended for the next attempt.
+    Retry([chat::ChatMessage; 2]),
+}
+
+/// The attempts of [Client::chat_validated](crate::v1::client::Client::chat_validated),
+/// shared by its sync and async versions.
+pub(crate) struct ChatValidationAttempts {
+    attempts: Vec<ChatValidationAttempt>,
+    usage: common::ResponseUsage,
+    max_retries: u32,
+}
+impl ChatValidationAttempts {
+    pub(crate) fn new(max_retries: u32) -> Self {
+        Self {
+            attempts: Vec::new(),
+            usage: common::ResponseUsage::default(),
+            max_retries,
+        }
+    }
+
+    /// Returns the error of a failed attempt request, with the previous attempts.
+    pub(crate) fn get_api_error(&mut self, error: error::ApiError) -> error::ChatValidationError {
+        error::ChatValidationError::Api {
+            error,
+            attempts: std::mem::take(&mut self.attempts),
+            usage: Box::new(self.usage.clone()),
+        }
+    }
+
+    /// Records and validates an attempt response.
+    pub(crate) fn validate<T>(
+        &mut self,
+        response: chat::ChatResponse,
+        validator: &impl ChatValidator<T>,
+    ) -> ChatValidationStep<T> {
+        self.usage += response.usage.clone();
+
+        let (message, validation_result) = match validate_choices(&response, validator) {
+            Some(validated_choice) => validated_choice,
+            None => {
+                self.attempts.push(ChatValidationAttempt {
+                    response,
+                    error: None,
+                });
+
+                return ChatValidationStep::Done(Err(error::ChatValidationError::MissingChoice {
+                    attempts: std::mem::take(&mut self.attempts),
+                    usage: Box::new(self.usage.clone()),
+                }));
+            }
+        };
+        match validation_result {
+            Ok(value) => {
+                self.attempts.push(ChatValidationAttempt {
+                    response,
+                    error: None,
+                });
+
+                ChatValidationStep::Done(Ok(ChatValidationResult {
+                    value,
+                    attempts: std::mem::take(&mut self.attempts),
+                    usage: self.usage.clone(),
+                }))
+            }
+            Err(validation_error) => {
+                debug!("Invalid assistant message content: {}", validation_error);
+                self.attempts.push(ChatValidationAttempt {
+                    response,
+                    error: Some(validation_error.clone()),
+                });
+                if self.attempts.len() as u32 > self.max_retries {
+                    return ChatValidationStep::Done(Err(
+                        error::ChatValidationError::InvalidContent {
+                            attempts: std::mem::take(&mut self.attempts),
+                            usage: Box::new(self.usage.clone()),
+                            message: validation_error,
+                            raw_content: message.content.text(),
+                        },
+                    ));
+                }
+
+                ChatValidationStep::Retry(get_correction_messages(&message, &validation_error))
+            }
+        }
+    }
+}
+
+/// Returns the messages to send for the next attempt, after an invalid assistant message.
+fn get_correction_messages(
+    assistant_message: &chat::ChatMessage,
+    error: &str,
+) -> [chat::ChatMessage; 2] {
+    [
+        assistant_message.without_thinking(),
+        chat::ChatMessage::new_user_message(CORRECTION_PROMPT.replace("{error}", error)),
+    ]
+}
+
+/// Validates the response choices in order, returning the first valid one,
+/// or the first invalid one when none is valid.
+fn validate_choices<T>(
+    response: &chat::ChatResponse,
+    validator: &impl ChatValidator<T>,
+) -> Option<(chat::ChatMessage, Result<T, String>)> {
+    let mut first_invalid: Option<(chat::ChatMessage, Result<T, String>)> = None;
+    for choice in &response.choices {
+        match validator.validate(&choice.message.content.text()) {
+            Ok(value) => return Some((choice.message.clone(), Ok(value))),
+            Err(error) => {
+                if first_invalid.is_none() {
+                    first_invalid = Some((choice.message.clone(), Err(error)));
+                }
+            }
+        }
+    }
+
+    first_invalid
+}
@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::v1::{chat, constants};
+
+// -----------------------------------------------------------------------------
+// Request
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClassificationRequest {
+    /// A classifier model, i.e. a model fine-tuned with a `Classifier` job.
+    pub model: constants::Model,
+    /// The texts to classify, each one getting its own result.
+    pub input: Vec<String>,
+}
+impl ClassificationRequest {
+    pub fn new(model: constants::Model, input: Vec<String>) -> Self {
+        Self { model, input }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClassificationChatRequest {
+    /// A classifier model, i.e. a model fine-tuned with a `Classifier` job.
+    pub model: constants::Model,
+    /// The conversations to classify, each one getting its own result.
+    pub input: Vec<ClassificationChatInput>,
+}
+impl ClassificationChatRequest {
+    pub fn new(model: constants::Model, input: Vec<Vec<chat::ChatMessage>>) -> Self {
+        Self {
+            model,
+            input: input
+                .into_iter()
+                .map(|messages| ClassificationChatInput { messages })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClassificationChatInput {
+    pub messages: Vec<chat::ChatMessage>,
+}
+
+/// The parameters of [Client::classify_batched](crate::v1::client::Client::classify_batched).
+#[derive(Clone, Debug)]
+pub struct ClassificationBatchParams {
+    /// The maximum number of texts sent per request.
+    ///
+    /// Defaults to `64`.
+    pub batch_size: usize,
+    /// The maximum number of requests sent at the same time (async only).
+    ///
+    /// Defaults to `4`.
+    pub concurrency: usize,
+}
+impl Default for ClassificationBatchParams {
+    fn default() -> Self {
+        Self {
+            batch_size: 64,
+            concurrency: 4,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClassificationResponse {
+    pub id: String,
+    pub model: constants::Model,
+    /// The results, in the same order as the request input.
+    pub results: Vec<ClassificationResult>,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The result of an input, per classifier target name (e.g. `priority` or `team`).
+pub type ClassificationResult = HashMap<String, ClassificationTargetResult>;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClassificationTargetResult {
+    /// The score of each label of the target.
+    pub scores: HashMap<String, f32>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl ClassificationTargetResult {
+    /// Returns the label with the highest score, for single-class targets.
+    pub fn get_top_label(&self) -> Option<(&str, f32)> {
+        self.scores
+            .iter()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(label, score)| (label.as_str(), *score))
+    }
+
+    /// Returns the labels whose score reaches the threshold, sorted by decreasing score,
+    /// for multi-class targets.
+    pub fn get_labels_above(&self, threshold: f32) -> Vec<(&str, f32)> {
+        let mut labels: Vec<(&str, f32)> = self
+            .scores
+            .iter()
+            .filter(|(_, score)| **score >= threshold)
+            .map(|(label, score)| (label.as_str(), *score))
+            .collect();
+        labels.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        labels
+    }
+}
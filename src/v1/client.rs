@@ -1,14 +1,22 @@
-use futures::stream::StreamExt;
-use futures::Stream;
-use log::debug;
+use futures::{Stream, StreamExt};
+use log::{debug, warn};
 use reqwest::Error as ReqwestError;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use std::{
     any::Any,
     collections::HashMap,
+    io::Write,
+    path::Path,
     sync::{Arc, Mutex},
 };
+use tokio::io::{AsyncSeekExt, AsyncWrite, AsyncWriteExt};
 
-use crate::v1::{chat, chat_stream, constants, embedding, error, model_list, tool, utils};
+use crate::v1::{
+    batch, batch_runner, chat, chat_classification, chat_stream, chat_validation, classification,
+    constants, embedding, error, file, fim, fine_tuning, model, model_list, model_registry,
+    moderation, ocr, tool, utils,
+};
 
 #[derive(Debug)]
 pub struct Client {
@@ -17,8 +25,33 @@ pub struct Client {
     pub max_retries: u32,
     pub timeout: u32,
 
-    functions: Arc<Mutex<HashMap<String, Box<dyn tool::Function>>>>,
+    endpoint_overrides: Vec<(ClientRoute, ClientEndpoint)>,
+    functions: Arc<Mutex<HashMap<String, Arc<dyn tool::Function>>>>,
     last_function_call_result: Arc<Mutex<Option<Box<dyn Any + Send>>>>,
+    model_registry: Option<model_registry::ModelRegistry>,
+    model_registry_cache: Arc<Mutex<Option<model_registry::ModelRegistryCache>>>,
+    /// Held while refreshing the model registry cache, so that only one caller fetches the models.
+    /// The sync methods can't hold a tokio mutex (which panics within a runtime), hence a mutex per context.
+    model_registry_refresh: Arc<tokio::sync::Mutex<()>>,
+    model_registry_refresh_sync: Arc<Mutex<()>>,
+    model_resolution: Option<model_registry::ModelResolutionParams>,
+}
+
+/// An API endpoint along with the API key used to call it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClientEndpoint {
+    /// The API base URL, e.g. [constants::CODESTRAL_API_URL_BASE].
+    pub url: String,
+    pub api_key: String,
+}
+
+/// The requests a [ClientEndpoint] override applies to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClientRoute {
+    /// Requests whose path starts with this one, e.g. `/fim`.
+    Path(String),
+    /// Requests targeting this model ID, e.g. `codestral-latest`.
+    Model(String),
 }
 
 impl Client {
@@ -27,7 +60,7 @@ impl Client {
     /// # Arguments
     ///
     /// * `api_key`     - An optional API key.
-    ///                   If not provided, the method will try to use the `MISTRAL_API_KEY` environment variable.
+    ///   If not provided, the method will try to use the `MISTRAL_API_KEY` environment variable.
     /// * `endpoint`    - An optional custom API endpoint. Defaults to the official API endpoint if not provided.
     /// * `max_retries` - Optional maximum number of retries for failed requests. Defaults to `5`.
     /// * `timeout`     - Optional timeout in seconds for requests. Defaults to `120`.
@@ -70,8 +103,14 @@ impl Client {
             max_retries,
             timeout,
 
+            endpoint_overrides: Vec::new(),
             functions,
             last_function_call_result,
+            model_registry: None,
+            model_registry_cache: Arc::new(Mutex::new(None)),
+            model_registry_refresh: Arc::new(tokio::sync::Mutex::new(())),
+            model_registry_refresh_sync: Arc::new(Mutex::new(())),
+            model_resolution: None,
         })
     }
 
@@ -92,40 +131,26 @@ impl Client {
     ///
     /// ```
     /// use mistralai_client::v1::{
-    ///     chat::{ChatMessage, ChatMessageRole},
+    ///     chat::ChatMessage,
     ///     client::Client,
     ///     constants::Model,
     /// };
     ///
     /// let client = Client::new(None, None, None, None).unwrap();
-    /// let messages = vec![ChatMessage {
-    ///     role: ChatMessageRole::User,
-    ///     content: "Hello, world!".to_string(),
-    ///     tool_calls: None,
-    /// }];
+    /// let messages = vec![ChatMessage::new_user_message("Hello, world!")];
     /// let response = client.chat(Model::OpenMistral7b, messages, None).unwrap();
     /// println!("{:?}: {}", response.choices[0].message.role, response.choices[0].message.content);
     /// ```
     pub fn chat(
         &self,
-        model: constants::Model,
+        model: impl Into<constants::Model>,
         messages: Vec<chat::ChatMessage>,
         options: Option<chat::ChatParams>,
     ) -> Result<chat::ChatResponse, error::ApiError> {
-        let request = chat::ChatRequest::new(model, messages, false, options);
-
-        let response = self.post_sync("/chat/completions", &request)?;
-        let result = response.json::<chat::ChatResponse>();
-        match result {
-            Ok(data) => {
-                utils::debug_pretty_json_from_struct("Response Data", &data);
+        let data = self.chat_without_functions(model.into(), messages, options)?;
+        self.call_function_if_any(data.clone());
 
-                self.call_function_if_any(data.clone());
-
-                Ok(data)
-            }
-            Err(error) => Err(self.to_api_error(error)),
-        }
+        Ok(data)
     }
 
     /// Asynchronously sends a chat completion request and returns the response.
@@ -145,7 +170,7 @@ impl Client {
     ///
     /// ```
     /// use mistralai_client::v1::{
-    ///     chat::{ChatMessage, ChatMessageRole},
+    ///     chat::ChatMessage,
     ///     client::Client,
     ///     constants::Model,
     /// };
@@ -153,34 +178,226 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Client::new(None, None, None, None).unwrap();
-    ///     let messages = vec![ChatMessage {
-    ///         role: ChatMessageRole::User,
-    ///         content: "Hello, world!".to_string(),
-    ///         tool_calls: None,
-    ///     }];
+    ///     let messages = vec![ChatMessage::new_user_message("Hello, world!")];
     ///     let response = client.chat_async(Model::OpenMistral7b, messages, None).await.unwrap();
     ///     println!("{:?}: {}", response.choices[0].message.role, response.choices[0].message.content);
     /// }
     /// ```
     pub async fn chat_async(
         &self,
-        model: constants::Model,
+        model: impl Into<constants::Model>,
         messages: Vec<chat::ChatMessage>,
         options: Option<chat::ChatParams>,
     ) -> Result<chat::ChatResponse, error::ApiError> {
-        let request = chat::ChatRequest::new(model, messages, false, options);
+        let data = self
+            .chat_without_functions_async(model.into(), messages, options)
+            .await?;
+        self.call_function_if_any_async(data.clone()).await;
 
-        let response = self.post_async("/chat/completions", &request).await?;
-        let result = response.json::<chat::ChatResponse>().await;
-        match result {
-            Ok(data) => {
-                utils::debug_pretty_json_from_struct("Response Data", &data);
+        Ok(data)
+    }
+
+    /// Synchronously sends a chat completion request and returns the assistant message parsed into `T`.
+    ///
+    /// Unless `options` already sets a `response_format`, the model output is constrained
+    /// to the JSON Schema derived from `T`, in strict mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{chat::ChatMessage, client::Client, constants::Model};
+    /// use schemars::JsonSchema;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Debug, Deserialize, JsonSchema)]
+    /// struct City {
+    ///     name: String,
+    ///     country: String,
+    /// }
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let messages = vec![ChatMessage::new_user_message("Which city is the Eiffel Tower in?")];
+    /// let city = client
+    ///     .chat_parsed::<City>(Model::MistralSmallLatest, messages, None)
+    ///     .unwrap();
+    /// println!("{:?}", city);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method fails whenever the request fails or the assistant message isn't a valid `T`,
+    /// in which case the error carries the raw message content.
+    pub fn chat_parsed<T: JsonSchema + DeserializeOwned>(
+        &self,
+        model: impl Into<constants::Model>,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+    ) -> Result<T, error::ChatParseError> {
+        let options = with_json_schema_response_format::<T>(options);
+        let response = self.chat(model, messages, Some(options))?;
+
+        response.parse_content::<T>()
+    }
+
+    /// Asynchronously sends a chat completion request and returns the assistant message parsed into `T`.
+    ///
+    /// See [Client::chat_parsed] for more information.
+    pub async fn chat_parsed_async<T: JsonSchema + DeserializeOwned>(
+        &self,
+        model: impl Into<constants::Model>,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+    ) -> Result<T, error::ChatParseError> {
+        let options = with_json_schema_response_format::<T>(options);
+        let response = self.chat_async(model, messages, Some(options)).await?;
 
-                self.call_function_if_any_async(data.clone()).await;
+        response.parse_content::<T>()
+    }
 
-                Ok(data)
+    /// Synchronously classifies the `input` by forcing the model to pick one of the `T` labels.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::impl_chat_classification;
+    /// use mistralai_client::v1::{client::Client, constants::Model};
+    ///
+    /// #[derive(Debug)]
+    /// enum Department {
+    ///     Billing,
+    ///     Support,
+    /// }
+    /// impl_chat_classification!(Department {
+    ///     Billing => ("billing", "Invoices, payments and refunds."),
+    ///     Support => ("support", "Technical issues with the product."),
+    /// });
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let result = client
+    ///     .chat_classify::<Department>(Model::MistralSmallLatest, "I was charged twice.", None)
+    ///     .unwrap();
+    /// println!("{:?}", result.label);
+    /// ```
+    pub fn chat_classify<T: chat_classification::ChatClassification>(
+        &self,
+        model: impl Into<constants::Model>,
+        input: &str,
+        options: Option<chat_classification::ChatClassificationParams>,
+    ) -> Result<chat_classification::ChatClassificationResult<T>, error::ChatParseError> {
+        let (messages, options) =
+            chat_classification::get_classification_request::<T>(input, options);
+        // The forced classification call must not run a registered function of the same name.
+        let response = self.chat_without_functions(model.into(), messages, Some(options))?;
+
+        chat_classification::parse_classification_response::<T>(response)
+    }
+
+    /// Asynchronously classifies the `input` by forcing the model to pick one of the `T` labels.
+    ///
+    /// See [Client::chat_classify] for more information.
+    pub async fn chat_classify_async<T: chat_classification::ChatClassification>(
+        &self,
+        model: impl Into<constants::Model>,
+        input: &str,
+        options: Option<chat_classification::ChatClassificationParams>,
+    ) -> Result<chat_classification::ChatClassificationResult<T>, error::ChatParseError> {
+        let (messages, options) =
+            chat_classification::get_classification_request::<T>(input, options);
+        let response = self
+            .chat_without_functions_async(model.into(), messages, Some(options))
+            .await?;
+
+        chat_classification::parse_classification_response::<T>(response)
+    }
+
+    /// Synchronously sends a chat completion request and validates the assistant message,
+    /// retrying with a corrective user message whenever it's invalid.
+    ///
+    /// When multiple choices are requested via `n`, the first valid choice is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The [Model] to use for the chat completion.
+    /// * `messages` - A vector of [ChatMessage] to send as part of the chat.
+    /// * `options` - Optional [ChatParams] to customize the request, e.g. with a JSON `response_format`.
+    /// * `validator` - The [ChatValidator] checking and converting the assistant message content.
+    /// * `max_retries` - The maximum number of retries after the first attempt.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{
+    ///     chat::{ChatMessage, ChatParams},
+    ///     chat_validation::SerdeValidator,
+    ///     client::Client,
+    ///     constants::Model,
+    /// };
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let messages = vec![ChatMessage::new_user_message(
+    ///     "List 3 French cities as a JSON array of strings.",
+    /// )];
+    /// let result = client
+    ///     .chat_validated(
+    ///         Model::MistralSmallLatest,
+    ///         messages,
+    ///         Some(ChatParams::json_default()),
+    ///         &SerdeValidator::<Vec<String>>::new(),
+    ///         2,
+    ///     )
+    ///     .unwrap();
+    /// println!("{:?} ({} attempts)", result.value, result.attempts.len());
+    /// ```
+    pub fn chat_validated<T>(
+        &self,
+        model: impl Into<constants::Model>,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+        validator: &impl chat_validation::ChatValidator<T>,
+        max_retries: u32,
+    ) -> Result<chat_validation::ChatValidationResult<T>, error::ChatValidationError> {
+        let model = model.into();
+        let mut messages = messages;
+        let mut attempts = chat_validation::ChatValidationAttempts::new(max_retries);
+        loop {
+            let response = self
+                .chat(model.clone(), messages.clone(), options.clone())
+                .map_err(|error| attempts.get_api_error(error))?;
+            match attempts.validate(response, validator) {
+                chat_validation::ChatValidationStep::Done(result) => return result,
+                chat_validation::ChatValidationStep::Retry(correction_messages) => {
+                    messages.extend(correction_messages)
+                }
+            }
+        }
+    }
+
+    /// Asynchronously sends a chat completion request and validates the assistant message,
+    /// retrying with a corrective user message whenever it's invalid.
+    ///
+    /// See [Client::chat_validated] for more information.
+    pub async fn chat_validated_async<T>(
+        &self,
+        model: impl Into<constants::Model>,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+        validator: &impl chat_validation::ChatValidator<T>,
+        max_retries: u32,
+    ) -> Result<chat_validation::ChatValidationResult<T>, error::ChatValidationError> {
+        let model = model.into();
+        let mut messages = messages;
+        let mut attempts = chat_validation::ChatValidationAttempts::new(max_retries);
+        loop {
+            let response = self
+                .chat_async(model.clone(), messages.clone(), options.clone())
+                .await
+                .map_err(|error| attempts.get_api_error(error))?;
+            match attempts.validate(response, validator) {
+                chat_validation::ChatValidationStep::Done(result) => return result,
+                chat_validation::ChatValidationStep::Retry(correction_messages) => {
+                    messages.extend(correction_messages)
+                }
             }
-            Err(error) => Err(self.to_api_error(error)),
         }
     }
 
@@ -202,7 +419,7 @@ impl Client {
     /// ```
     /// use futures::stream::StreamExt;
     /// use mistralai_client::v1::{
-    ///     chat::{ChatMessage, ChatMessageRole},
+    ///     chat::ChatMessage,
     ///     client::Client,
     ///     constants::Model,
     /// };
@@ -211,11 +428,7 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Client::new(None, None, None, None).unwrap();
-    ///     let messages = vec![ChatMessage {
-    ///         role: ChatMessageRole::User,
-    ///         content: "Hello, world!".to_string(),
-    ///         tool_calls: None,
-    ///     }];
+    ///     let messages = vec![ChatMessage::new_user_message("Hello, world!")];
     ///
     ///     let stream_result = client
     ///         .chat_stream(Model::OpenMistral7b,messages, None)
@@ -235,72 +448,239 @@ impl Client {
     ///             }
     ///         })
     ///         .await;
-    ///     print!("\n") // To persist the last chunk output.
+    ///     println!() // To persist the last chunk output.
     /// }
     pub async fn chat_stream(
         &self,
-        model: constants::Model,
+        model: impl Into<constants::Model>,
         messages: Vec<chat::ChatMessage>,
         options: Option<chat::ChatParams>,
     ) -> Result<
         impl Stream<Item = Result<Vec<chat_stream::ChatStreamChunk>, error::ApiError>>,
         error::ApiError,
     > {
+        let requested_model = model.into();
+        let model = self.resolve_model_async(requested_model.clone()).await;
         let request = chat::ChatRequest::new(model, messages, true, options);
+        self.check_chat_request(&request)?;
         let response = self
-            .post_stream("/chat/completions", &request)
-            .await
-            .map_err(|e| error::ApiError {
-                message: e.to_string(),
-            })?;
-        if !response.status().is_success() {
-            let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            return Err(error::ApiError {
-                message: format!("{}: {}", status, text),
-            });
-        }
-
-        let deserialized_stream = response.bytes_stream().then(|bytes_result| async move {
-            match bytes_result {
-                Ok(bytes) => match String::from_utf8(bytes.to_vec()) {
-                    Ok(message) => {
-                        let chunks = message
-                            .lines()
-                            .filter_map(
-                                |line| match chat_stream::get_chunk_from_stream_message_line(line) {
-                                    Ok(Some(chunks)) => Some(chunks),
-                                    Ok(None) => None,
-                                    Err(_error) => None,
-                                },
-                            )
-                            .flatten()
-                            .collect();
-
-                        Ok(chunks)
-                    }
-                    Err(e) => Err(error::ApiError {
-                        message: e.to_string(),
-                    }),
-                },
-                Err(e) => Err(error::ApiError {
-                    message: e.to_string(),
-                }),
+            .post_stream(
+                "/chat/completions",
+                Some(requested_model.as_str()),
+                &request,
+            )
+            .await?;
+
+        Ok(chat_stream::deserialize_stream_response(response))
+    }
+
+    /// Synchronously moderates texts and returns a result per text.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{client::Client, constants::Model};
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let input = vec!["I love this library!".to_string()];
+    /// let response = client.moderate(Model::MistralModerationLatest, input).unwrap();
+    /// println!("{}", response.results[0].is_flagged());
+    /// ```
+    pub fn moderate(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<String>,
+    ) -> Result<moderation::ModerationResponse, error::ApiError> {
+        let request = moderation::ModerationRequest::new(model.into(), input);
+        let response = self.post_sync("/moderations", Some(request.model.as_str()), &request)?;
+        let result = response.json::<moderation::ModerationResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
             }
-        });
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously moderates texts and returns a result per text.
+    pub async fn moderate_async(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<String>,
+    ) -> Result<moderation::ModerationResponse, error::ApiError> {
+        let request = moderation::ModerationRequest::new(model.into(), input);
+        let response = self
+            .post_async("/moderations", Some(request.model.as_str()), &request)
+            .await?;
+        let result = response.json::<moderation::ModerationResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
 
-        Ok(deserialized_stream)
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously moderates the last message of each conversation, in the context of the previous ones,
+    /// and returns a result per conversation.
+    pub fn moderate_chat(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<Vec<chat::ChatMessage>>,
+    ) -> Result<moderation::ModerationResponse, error::ApiError> {
+        let request = moderation::ChatModerationRequest::new(model.into(), input);
+        let response =
+            self.post_sync("/chat/moderations", Some(request.model.as_str()), &request)?;
+        let result = response.json::<moderation::ModerationResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously moderates the last message of each conversation, in the context of the previous ones,
+    /// and returns a result per conversation.
+    pub async fn moderate_chat_async(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<Vec<chat::ChatMessage>>,
+    ) -> Result<moderation::ModerationResponse, error::ApiError> {
+        let request = moderation::ChatModerationRequest::new(model.into(), input);
+        let response = self
+            .post_async("/chat/moderations", Some(request.model.as_str()), &request)
+            .await?;
+        let result = response.json::<moderation::ModerationResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously sends a chat completion request guarded by the moderation API.
+    ///
+    /// Depending on `guard`, the conversation last message is moderated before the chat request
+    /// and the assistant messages after it. With `ModerationAction::Block`, flagged content fails
+    /// the request, otherwise the flagged categories are returned along with the response.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{
+    ///     chat::ChatMessage,
+    ///     client::Client,
+    ///     constants::Model,
+    ///     error::ModerationError,
+    ///     moderation::{ModerationCategory, ModerationGuardParams},
+    /// };
+    /// use std::collections::HashMap;
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let messages = vec![ChatMessage::new_user_message("How do I pick a lock?")];
+    /// let guard = ModerationGuardParams {
+    ///     thresholds: HashMap::from([(ModerationCategory::DangerousAndCriminalContent, 0.5)]),
+    ///     ..Default::default()
+    /// };
+    /// match client.chat_moderated(Model::MistralSmallLatest, messages, None, Some(guard)) {
+    ///     Ok(moderated_response) => println!("{}", moderated_response.response.choices[0].message.content),
+    ///     Err(ModerationError::InputBlocked { categories }) => println!("Blocked: {:?}", categories),
+    ///     Err(error) => eprintln!("{}", error),
+    /// }
+    /// ```
+    pub fn chat_moderated(
+        &self,
+        model: impl Into<constants::Model>,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+        guard: Option<moderation::ModerationGuardParams>,
+    ) -> Result<moderation::ModeratedChatResponse, error::ModerationError> {
+        let guard = guard.unwrap_or_default();
+
+        let mut input_flagged_categories = Vec::new();
+        if guard.moderate_input {
+            let moderation = self.moderate_chat(guard.model.clone(), vec![messages.clone()])?;
+            input_flagged_categories = moderation.get_flagged_categories(&guard.thresholds);
+            check_moderation_input(&guard, &input_flagged_categories)?;
+        }
+
+        let response = self.chat(model, messages.clone(), options)?;
+
+        let mut output_flagged_categories = Vec::new();
+        if guard.moderate_output && !response.choices.is_empty() {
+            let input = get_moderation_output_input(&messages, &response);
+            let moderation = self.moderate_chat(guard.model.clone(), input)?;
+            output_flagged_categories = moderation.get_flagged_categories(&guard.thresholds);
+        }
+
+        get_moderated_chat_response(
+            &guard,
+            response,
+            input_flagged_categories,
+            output_flagged_categories,
+        )
+    }
+
+    /// Asynchronously sends a chat completion request guarded by the moderation API.
+    ///
+    /// See [Client::chat_moderated] for more information.
+    pub async fn chat_moderated_async(
+        &self,
+        model: impl Into<constants::Model>,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+        guard: Option<moderation::ModerationGuardParams>,
+    ) -> Result<moderation::ModeratedChatResponse, error::ModerationError> {
+        let guard = guard.unwrap_or_default();
+
+        let mut input_flagged_categories = Vec::new();
+        if guard.moderate_input {
+            let moderation = self
+                .moderate_chat_async(guard.model.clone(), vec![messages.clone()])
+                .await?;
+            input_flagged_categories = moderation.get_flagged_categories(&guard.thresholds);
+            check_moderation_input(&guard, &input_flagged_categories)?;
+        }
+
+        let response = self.chat_async(model, messages.clone(), options).await?;
+
+        let mut output_flagged_categories = Vec::new();
+        if guard.moderate_output && !response.choices.is_empty() {
+            let input = get_moderation_output_input(&messages, &response);
+            let moderation = self.moderate_chat_async(guard.model.clone(), input).await?;
+            output_flagged_categories = moderation.get_flagged_categories(&guard.thresholds);
+        }
+
+        get_moderated_chat_response(
+            &guard,
+            response,
+            input_flagged_categories,
+            output_flagged_categories,
+        )
     }
 
     pub fn embeddings(
         &self,
-        model: constants::EmbedModel,
+        model: impl Into<constants::EmbedModel>,
         input: Vec<String>,
         options: Option<embedding::EmbeddingRequestOptions>,
     ) -> Result<embedding::EmbeddingResponse, error::ApiError> {
-        let request = embedding::EmbeddingRequest::new(model, input, options);
+        let requested_model = model.into();
+        let model_id = self.resolve_model_id_sync(requested_model.as_str());
+        let request =
+            embedding::EmbeddingRequest::new(constants::EmbedModel::new(&model_id), input, options);
 
-        let response = self.post_sync("/embeddings", &request)?;
+        let response = self.post_sync("/embeddings", Some(requested_model.as_str()), &request)?;
         let result = response.json::<embedding::EmbeddingResponse>();
         match result {
             Ok(data) => {
@@ -314,13 +694,18 @@ impl Client {
 
     pub async fn embeddings_async(
         &self,
-        model: constants::EmbedModel,
+        model: impl Into<constants::EmbedModel>,
         input: Vec<String>,
         options: Option<embedding::EmbeddingRequestOptions>,
     ) -> Result<embedding::EmbeddingResponse, error::ApiError> {
-        let request = embedding::EmbeddingRequest::new(model, input, options);
+        let requested_model = model.into();
+        let model_id = self.resolve_model_id_async(requested_model.as_str()).await;
+        let request =
+            embedding::EmbeddingRequest::new(constants::EmbedModel::new(&model_id), input, options);
 
-        let response = self.post_async("/embeddings", &request).await?;
+        let response = self
+            .post_async("/embeddings", Some(requested_model.as_str()), &request)
+            .await?;
         let result = response.json::<embedding::EmbeddingResponse>().await;
         match result {
             Ok(data) => {
@@ -332,15 +717,52 @@ impl Client {
         }
     }
 
-    pub fn get_last_function_call_result(&self) -> Option<Box<dyn Any + Send>> {
-        let mut result_lock = self.last_function_call_result.lock().unwrap();
+    /// Synchronously classifies texts with a classifier model and returns a result per text.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::client::Client;
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let input = vec!["The app crashes when I upload a file.".to_string()];
+    /// let response = client.classify("ft:ministral-3b-latest:xxx:triage", input).unwrap();
+    /// if let Some((label, score)) = response.results[0]["priority"].get_top_label() {
+    ///     println!("{} ({})", label, score);
+    /// }
+    /// ```
+    pub fn classify(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<String>,
+    ) -> Result<classification::ClassificationResponse, error::ApiError> {
+        let request = classification::ClassificationRequest::new(model.into(), input);
+        let response =
+            self.post_sync("/classifications", Some(request.model.as_str()), &request)?;
+        let result = response.json::<classification::ClassificationResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
 
-        result_lock.take()
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
     }
 
-    pub fn list_models(&self) -> Result<model_list::ModelListResponse, error::ApiError> {
-        let response = self.get_sync("/models")?;
-        let result = response.json::<model_list::ModelListResponse>();
+    /// Asynchronously classifies texts with a classifier model and returns a result per text.
+    pub async fn classify_async(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<String>,
+    ) -> Result<classification::ClassificationResponse, error::ApiError> {
+        let request = classification::ClassificationRequest::new(model.into(), input);
+        let response = self
+            .post_async("/classifications", Some(request.model.as_str()), &request)
+            .await?;
+        let result = response
+            .json::<classification::ClassificationResponse>()
+            .await;
         match result {
             Ok(data) => {
                 utils::debug_pretty_json_from_struct("Response Data", &data);
@@ -351,11 +773,19 @@ impl Client {
         }
     }
 
-    pub async fn list_models_async(
+    /// Synchronously classifies conversations with a classifier model and returns a result per conversation.
+    pub fn classify_chat(
         &self,
-    ) -> Result<model_list::ModelListResponse, error::ApiError> {
-        let response = self.get_async("/models").await?;
-        let result = response.json::<model_list::ModelListResponse>().await;
+        model: impl Into<constants::Model>,
+        input: Vec<Vec<chat::ChatMessage>>,
+    ) -> Result<classification::ClassificationResponse, error::ApiError> {
+        let request = classification::ClassificationChatRequest::new(model.into(), input);
+        let response = self.post_sync(
+            "/chat/classifications",
+            Some(request.model.as_str()),
+            &request,
+        )?;
+        let result = response.json::<classification::ClassificationResponse>();
         match result {
             Ok(data) => {
                 utils::debug_pretty_json_from_struct("Response Data", &data);
@@ -366,67 +796,2093 @@ impl Client {
         }
     }
 
-    pub fn register_function(&mut self, name: String, function: Box<dyn tool::Function>) {
-        let mut functions = self.functions.lock().unwrap();
+    /// Asynchronously classifies conversations with a classifier model and returns a result per conversation.
+    pub async fn classify_chat_async(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<Vec<chat::ChatMessage>>,
+    ) -> Result<classification::ClassificationResponse, error::ApiError> {
+        let request = classification::ClassificationChatRequest::new(model.into(), input);
+        let response = self
+            .post_async(
+                "/chat/classifications",
+                Some(request.model.as_str()),
+                &request,
+            )
+            .await?;
+        let result = response
+            .json::<classification::ClassificationResponse>()
+            .await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
 
-        functions.insert(name, function);
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
     }
 
-    fn build_request_sync(
+    /// Synchronously classifies any number of texts, split into requests of `batch_size` texts,
+    /// and returns a result per text, in the same order.
+    pub fn classify_batched(
         &self,
-        request: reqwest::blocking::RequestBuilder,
-    ) -> reqwest::blocking::RequestBuilder {
-        let user_agent = format!(
-            "ivangabriele/mistralai-client-rs/{}",
-            env!("CARGO_PKG_VERSION")
-        );
-
-        let request_builder = request
-            .bearer_auth(&self.api_key)
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("User-Agent", user_agent);
+        model: impl Into<constants::Model>,
+        input: Vec<String>,
+        options: Option<classification::ClassificationBatchParams>,
+    ) -> Result<Vec<classification::ClassificationResult>, error::ApiError> {
+        let model = model.into();
+        let options = options.unwrap_or_default();
+
+        let mut results = Vec::with_capacity(input.len());
+        for batch_input in input.chunks(options.batch_size.max(1)) {
+            let response = self.classify(model.clone(), batch_input.to_vec())?;
+            results.extend(response.results);
+        }
 
-        request_builder
+        Ok(results)
     }
 
-    fn build_request_async(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        let user_agent = format!(
-            "ivangabriele/mistralai-client-rs/{}",
-            env!("CARGO_PKG_VERSION")
-        );
-
-        let request_builder = request
-            .bearer_auth(&self.api_key)
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .header("User-Agent", user_agent);
+    /// Asynchronously classifies any number of texts, split into requests of `batch_size` texts
+    /// sent up to `concurrency` at a time, and returns a result per text, in the same order.
+    ///
+    /// See [Client::classify_batched] for more information.
+    pub async fn classify_batched_async(
+        &self,
+        model: impl Into<constants::Model>,
+        input: Vec<String>,
+        options: Option<classification::ClassificationBatchParams>,
+    ) -> Result<Vec<classification::ClassificationResult>, error::ApiError> {
+        let model = model.into();
+        let options = options.unwrap_or_default();
+
+        let mut responses = futures::stream::iter(input.chunks(options.batch_size.max(1)))
+            .map(|batch_input| self.classify_async(model.clone(), batch_input.to_vec()))
+            .buffered(options.concurrency.max(1));
+        let mut results = Vec::with_capacity(input.len());
+        while let Some(response) = responses.next().await {
+            results.extend(response?.results);
+        }
 
-        request_builder
+        Ok(results)
     }
 
-    fn build_request_stream(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        let user_agent = format!(
-            "ivangabriele/mistralai-client-rs/{}",
+    /// Synchronously extracts the content of a document (or image) as markdown, page by page,
+    /// with its embedded images.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The [Model] to use, e.g. `Model::MistralOcrLatest`.
+    /// * `document` - The [OcrDocument](ocr::OcrDocument) to process: a document URL, an image URL or an uploaded file.
+    /// * `options` - Optional [OcrParams](ocr::OcrParams) to customize the request, e.g. to get the images data or
+    ///   structured annotations.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{
+    ///     client::Client,
+    ///     constants::Model,
+    ///     ocr::{OcrDocument, OcrParams},
+    /// };
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let document = OcrDocument::new_document_url("https://arxiv.org/pdf/2201.04234");
+    /// let options = OcrParams {
+    ///     include_image_base64: Some(true),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let response = client.ocr(Model::MistralOcrLatest, document, Some(options)).unwrap();
+    /// println!("{}", response.get_markdown());
+    /// response.save_images("./images").unwrap();
+    /// ```
+    pub fn ocr(
+        &self,
+        model: impl Into<constants::Model>,
+        document: ocr::OcrDocument,
+        options: Option<ocr::OcrParams>,
+    ) -> Result<ocr::OcrResponse, error::ApiError> {
+        let request = ocr::OcrRequest::new(model.into(), document, options);
+        let response = self.post_sync("/ocr", Some(request.model.as_str()), &request)?;
+        let result = response.json::<ocr::OcrResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously extracts the content of a document (or image) as markdown, page by page,
+    /// with its embedded images.
+    ///
+    /// See [Client::ocr] for more information.
+    pub async fn ocr_async(
+        &self,
+        model: impl Into<constants::Model>,
+        document: ocr::OcrDocument,
+        options: Option<ocr::OcrParams>,
+    ) -> Result<ocr::OcrResponse, error::ApiError> {
+        let request = ocr::OcrRequest::new(model.into(), document, options);
+        let response = self
+            .post_async("/ocr", Some(request.model.as_str()), &request)
+            .await?;
+        let result = response.json::<ocr::OcrResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously sends a fill-in-the-middle (FIM) completion request and returns the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The [Model] to use for the completion, e.g. `Model::CodestralLatest`.
+    /// * `prompt` - The code preceding the completion.
+    /// * `options` - Optional [FimParams] to customize the request, e.g. with the code `suffix`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{client::Client, constants::Model, fim::FimParams};
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let options = FimParams {
+    ///     suffix: Some("\n    return a + b".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let response = client
+    ///     .fim(Model::CodestralLatest, "def add(a, b):".to_string(), Some(options))
+    ///     .unwrap();
+    /// println!("{}", response.choices[0].message.content);
+    /// ```
+    pub fn fim(
+        &self,
+        model: impl Into<constants::Model>,
+        prompt: String,
+        options: Option<fim::FimParams>,
+    ) -> Result<fim::FimResponse, error::ApiError> {
+        let requested_model = model.into();
+        let model = self.resolve_model_sync(requested_model.clone());
+        let request = fim::FimRequest::new(model, prompt, false, options);
+        self.check_fim_request(&request)?;
+
+        let response =
+            self.post_sync("/fim/completions", Some(requested_model.as_str()), &request)?;
+        let result = response.json::<fim::FimResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously sends a fill-in-the-middle (FIM) completion request and returns the response.
+    ///
+    /// See [Client::fim] for more information.
+    pub async fn fim_async(
+        &self,
+        model: impl Into<constants::Model>,
+        prompt: String,
+        options: Option<fim::FimParams>,
+    ) -> Result<fim::FimResponse, error::ApiError> {
+        let requested_model = model.into();
+        let model = self.resolve_model_async(requested_model.clone()).await;
+        let request = fim::FimRequest::new(model, prompt, false, options);
+        self.check_fim_request(&request)?;
+
+        let response = self
+            .post_async("/fim/completions", Some(requested_model.as_str()), &request)
+            .await?;
+        let result = response.json::<fim::FimResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously sends a fill-in-the-middle (FIM) completion request and returns a stream of completion chunks.
+    ///
+    /// See [Client::fim] and [Client::chat_stream] for more information.
+    pub async fn fim_stream(
+        &self,
+        model: impl Into<constants::Model>,
+        prompt: String,
+        options: Option<fim::FimParams>,
+    ) -> Result<
+        impl Stream<Item = Result<Vec<fim::FimStreamChunk>, error::ApiError>>,
+        error::ApiError,
+    > {
+        let requested_model = model.into();
+        let model = self.resolve_model_async(requested_model.clone()).await;
+        let request = fim::FimRequest::new(model, prompt, true, options);
+        self.check_fim_request(&request)?;
+        let response = self
+            .post_stream("/fim/completions", Some(requested_model.as_str()), &request)
+            .await?;
+
+        Ok(chat_stream::deserialize_stream_response(response))
+    }
+
+    pub fn get_last_function_call_result(&self) -> Option<Box<dyn Any + Send>> {
+        let mut result_lock = self.last_function_call_result.lock().unwrap();
+
+        result_lock.take()
+    }
+
+    /// Synchronously uploads a file, streaming it from the disk.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file to upload (e.g. a JSONL fine-tuning dataset).
+    /// * `purpose` - What the file is used for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{client::Client, file::FilePurpose};
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let file = client
+    ///     .upload_file("training_file.jsonl", FilePurpose::FineTune)
+    ///     .unwrap();
+    /// println!("Uploaded file ID: {}", file.id);
+    /// ```
+    pub fn upload_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        purpose: file::FilePurpose,
+    ) -> Result<file::FileResponse, error::ApiError> {
+        let path = path.as_ref();
+        let file_name = get_file_name(path);
+        let file = std::fs::File::open(path).map_err(to_file_api_error)?;
+        let file_size = file.metadata().map_err(to_file_api_error)?.len();
+
+        let part = reqwest::blocking::multipart::Part::reader_with_length(file, file_size)
+            .file_name(file_name);
+        let form = reqwest::blocking::multipart::Form::new()
+            .text("purpose", purpose.to_string())
+            .part("file", part);
+
+        let response = self.post_multipart_sync("/files", form)?;
+        let result = response.json::<file::FileResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously uploads a file, streaming it from the disk.
+    ///
+    /// See [Client::upload_file] for more information.
+    pub async fn upload_file_async<P: AsRef<Path>>(
+        &self,
+        path: P,
+        purpose: file::FilePurpose,
+    ) -> Result<file::FileResponse, error::ApiError> {
+        let path = path.as_ref();
+        let file_name = get_file_name(path);
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(to_file_api_error)?;
+        let file_size = file.metadata().await.map_err(to_file_api_error)?.len();
+
+        let body = reqwest::Body::wrap_stream(tokio_util::io::ReaderStream::new(file));
+        let part =
+            reqwest::multipart::Part::stream_with_length(body, file_size).file_name(file_name);
+        let form = reqwest::multipart::Form::new()
+            .text("purpose", purpose.to_string())
+            .part("file", part);
+
+        let response = self.post_multipart_async("/files", form).await?;
+        let result = response.json::<file::FileResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously lists the uploaded files, by page.
+    pub fn list_files(
+        &self,
+        options: Option<file::FileListParams>,
+    ) -> Result<file::FileListResponse, error::ApiError> {
+        let request = file::FileListRequest::new(options);
+        let response = self.get_sync(&format!("/files{}", utils::get_query_string(&request)))?;
+        let result = response.json::<file::FileListResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously lists the uploaded files, by page.
+    pub async fn list_files_async(
+        &self,
+        options: Option<file::FileListParams>,
+    ) -> Result<file::FileListResponse, error::ApiError> {
+        let request = file::FileListRequest::new(options);
+        let response = self
+            .get_async(&format!("/files{}", utils::get_query_string(&request)))
+            .await?;
+        let result = response.json::<file::FileListResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously retrieves the metadata of an uploaded file.
+    pub fn get_file(&self, file_id: &str) -> Result<file::FileResponse, error::ApiError> {
+        let response = self.get_sync(&format!("/files/{}", file_id))?;
+        let result = response.json::<file::FileResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously retrieves the metadata of an uploaded file.
+    pub async fn get_file_async(
+        &self,
+        file_id: &str,
+    ) -> Result<file::FileResponse, error::ApiError> {
+        let response = self.get_async(&format!("/files/{}", file_id)).await?;
+        let result = response.json::<file::FileResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously downloads the content of an uploaded file into the `writer`,
+    /// returning the number of written bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::client::Client;
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let mut output_file = std::fs::File::create("batch_results.jsonl").unwrap();
+    /// client.download_file("file_id", &mut output_file).unwrap();
+    /// ```
+    pub fn download_file<W: Write>(
+        &self,
+        file_id: &str,
+        writer: &mut W,
+    ) -> Result<u64, error::ApiError> {
+        let mut response = self.get_download_sync(&format!("/files/{}/content", file_id))?;
+
+        response
+            .copy_to(writer)
+            .map_err(|error| self.to_api_error(error))
+    }
+
+    /// Asynchronously downloads the content of an uploaded file into the `writer`,
+    /// returning the number of written bytes.
+    ///
+    /// See [Client::download_file] for more information.
+    pub async fn download_file_async<W: AsyncWrite + Unpin>(
+        &self,
+        file_id: &str,
+        writer: &mut W,
+    ) -> Result<u64, error::ApiError> {
+        let response = self
+            .get_download_async(&format!("/files/{}/content", file_id))
+            .await?;
+
+        let mut size: u64 = 0;
+        let mut bytes_stream = response.bytes_stream();
+        while let Some(bytes_result) = bytes_stream.next().await {
+            let bytes = bytes_result.map_err(|error| self.to_api_error(error))?;
+            writer.write_all(&bytes).await.map_err(to_file_api_error)?;
+            size += bytes.len() as u64;
+        }
+        writer.flush().await.map_err(to_file_api_error)?;
+
+        Ok(size)
+    }
+
+    /// Synchronously retrieves a temporary URL to download an uploaded file content, valid for `expiry` hours
+    /// (defaults to 24 hours).
+    pub fn get_file_signed_url(
+        &self,
+        file_id: &str,
+        expiry: Option<u32>,
+    ) -> Result<file::FileSignedUrlResponse, error::ApiError> {
+        let request = file::FileSignedUrlRequest { expiry };
+        let response = self.get_sync(&format!(
+            "/files/{}/url{}",
+            file_id,
+            utils::get_query_string(&request)
+        ))?;
+        let result = response.json::<file::FileSignedUrlResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously retrieves a temporary URL to download an uploaded file content.
+    ///
+    /// See [Client::get_file_signed_url] for more information.
+    pub async fn get_file_signed_url_async(
+        &self,
+        file_id: &str,
+        expiry: Option<u32>,
+    ) -> Result<file::FileSignedUrlResponse, error::ApiError> {
+        let request = file::FileSignedUrlRequest { expiry };
+        let response = self
+            .get_async(&format!(
+                "/files/{}/url{}",
+                file_id,
+                utils::get_query_string(&request)
+            ))
+            .await?;
+        let result = response.json::<file::FileSignedUrlResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously deletes an uploaded file.
+    pub fn delete_file(&self, file_id: &str) -> Result<file::FileDeleteResponse, error::ApiError> {
+        let response = self.delete_sync(&format!("/files/{}", file_id))?;
+        let result = response.json::<file::FileDeleteResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously deletes an uploaded file.
+    pub async fn delete_file_async(
+        &self,
+        file_id: &str,
+    ) -> Result<file::FileDeleteResponse, error::ApiError> {
+        let response = self.delete_async(&format!("/files/{}", file_id)).await?;
+        let result = response.json::<file::FileDeleteResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously creates a batch job from already uploaded input files.
+    ///
+    /// See [Client::submit_batch] to build and upload the input file from typed requests.
+    pub fn create_batch_job(
+        &self,
+        input_files: Vec<String>,
+        endpoint: batch::BatchEndpoint,
+        model: impl Into<constants::Model>,
+        options: Option<batch::BatchJobParams>,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let request = batch::BatchJobRequest::new(input_files, endpoint, model.into(), options);
+        let response = self.post_sync("/batch/jobs", Some(request.model.as_str()), &request)?;
+        let result = response.json::<batch::BatchJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously creates a batch job from already uploaded input files.
+    pub async fn create_batch_job_async(
+        &self,
+        input_files: Vec<String>,
+        endpoint: batch::BatchEndpoint,
+        model: impl Into<constants::Model>,
+        options: Option<batch::BatchJobParams>,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let request = batch::BatchJobRequest::new(input_files, endpoint, model.into(), options);
+        let response = self
+            .post_async("/batch/jobs", Some(request.model.as_str()), &request)
+            .await?;
+        let result = response.json::<batch::BatchJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously lists the batch jobs, by page.
+    pub fn list_batch_jobs(
+        &self,
+        options: Option<batch::BatchJobListParams>,
+    ) -> Result<batch::BatchJobListResponse, error::ApiError> {
+        let request = batch::BatchJobListRequest::new(options);
+        let response =
+            self.get_sync(&format!("/batch/jobs{}", utils::get_query_string(&request)))?;
+        let result = response.json::<batch::BatchJobListResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously lists the batch jobs, by page.
+    pub async fn list_batch_jobs_async(
+        &self,
+        options: Option<batch::BatchJobListParams>,
+    ) -> Result<batch::BatchJobListResponse, error::ApiError> {
+        let request = batch::BatchJobListRequest::new(options);
+        let response = self
+            .get_async(&format!("/batch/jobs{}", utils::get_query_string(&request)))
+            .await?;
+        let result = response.json::<batch::BatchJobListResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously retrieves a batch job.
+    pub fn get_batch_job(&self, job_id: &str) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let response = self.get_sync(&format!("/batch/jobs/{}", job_id))?;
+        let result = response.json::<batch::BatchJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously retrieves a batch job.
+    pub async fn get_batch_job_async(
+        &self,
+        job_id: &str,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let response = self.get_async(&format!("/batch/jobs/{}", job_id)).await?;
+        let result = response.json::<batch::BatchJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously requests the cancellation of a batch job.
+    pub fn cancel_batch_job(
+        &self,
+        job_id: &str,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let response = self.post_sync(
+            &format!("/batch/jobs/{}/cancel", job_id),
+            None,
+            &serde_json::json!({}),
+        )?;
+        let result = response.json::<batch::BatchJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously requests the cancellation of a batch job.
+    pub async fn cancel_batch_job_async(
+        &self,
+        job_id: &str,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let response = self
+            .post_async(
+                &format!("/batch/jobs/{}/cancel", job_id),
+                None,
+                &serde_json::json!({}),
+            )
+            .await?;
+        let result = response.json::<batch::BatchJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously uploads the requests as a batch input file and creates a batch job processing them.
+    ///
+    /// All the requests must target the same model.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{
+    ///     batch::BatchRequest,
+    ///     chat::{ChatMessage, ChatRequest, ChatResponse},
+    ///     client::Client,
+    ///     constants::Model,
+    /// };
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let requests: Vec<BatchRequest<ChatRequest>> = ["Paris", "Lyon"]
+    ///     .iter()
+    ///     .map(|city| {
+    ///         let messages = vec![ChatMessage::new_user_message(format!("Describe {}.", city))];
+    ///         BatchRequest::new(city, ChatRequest::new(Model::OpenMistral7b, messages, false, None))
+    ///     })
+    ///     .collect();
+    ///
+    /// let job = client.submit_batch(&requests, None).unwrap();
+    /// // The job ID can be saved to resume waiting for the job later on.
+    /// let job = client.wait_batch_job(&job.id, None).unwrap();
+    /// let results = client.get_batch_results::<ChatResponse>(&job).unwrap();
+    /// println!("{}", results.responses["Paris"].choices[0].message.content);
+    /// ```
+    pub fn submit_batch<T: batch::BatchRequestBody>(
+        &self,
+        requests: &[batch::BatchRequest<T>],
+        options: Option<batch::BatchJobParams>,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let (model_id, content) = get_batch_input(requests)?;
+        let part = reqwest::blocking::multipart::Part::bytes(content).file_name("batch.jsonl");
+        let form = reqwest::blocking::multipart::Form::new()
+            .text("purpose", file::FilePurpose::Batch.to_string())
+            .part("file", part);
+
+        let response = self.post_multipart_sync("/files", form)?;
+        let input_file = response
+            .json::<file::FileResponse>()
+            .map_err(|error| self.to_api_error(error))?;
+
+        self.create_batch_job(vec![input_file.id], T::endpoint(), model_id, options)
+    }
+
+    /// Asynchronously uploads the requests as a batch input file and creates a batch job processing them.
+    ///
+    /// See [Client::submit_batch] for more information.
+    pub async fn submit_batch_async<T: batch::BatchRequestBody>(
+        &self,
+        requests: &[batch::BatchRequest<T>],
+        options: Option<batch::BatchJobParams>,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let (model_id, content) = get_batch_input(requests)?;
+        let part = reqwest::multipart::Part::bytes(content).file_name("batch.jsonl");
+        let form = reqwest::multipart::Form::new()
+            .text("purpose", file::FilePurpose::Batch.to_string())
+            .part("file", part);
+
+        let response = self.post_multipart_async("/files", form).await?;
+        let input_file = response
+            .json::<file::FileResponse>()
+            .await
+            .map_err(|error| self.to_api_error(error))?;
+
+        self.create_batch_job_async(vec![input_file.id], T::endpoint(), model_id, options)
+            .await
+    }
+
+    /// Synchronously polls a batch job with an exponential backoff until it's over, and returns it.
+    ///
+    /// Since it only needs the job ID, it can resume waiting for a job submitted by another process.
+    ///
+    /// Retryable poll errors (see [ApiError::is_retryable](error::ApiError::is_retryable)) are
+    /// retried with the same backoff, up to [Client::max_retries] times in a row.
+    pub fn wait_batch_job(
+        &self,
+        job_id: &str,
+        options: Option<batch::BatchJobWaitParams>,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let options = options.unwrap_or_default();
+
+        let mut interval = options.initial_interval;
+        let mut failed_polls = 0;
+        loop {
+            match self.get_batch_job(job_id) {
+                Ok(job) => {
+                    failed_polls = 0;
+                    if job.status.is_terminal() {
+                        return Ok(job);
+                    }
+                    debug!(
+                        "Batch job `{}` is {:?} ({}/{} requests completed).",
+                        job.id, job.status, job.completed_requests, job.total_requests
+                    );
+                }
+                Err(error) if error.is_retryable() && failed_polls < self.max_retries => {
+                    failed_polls += 1;
+                    warn!(
+                        "Failed to poll the `{}` batch job (retry {}/{}): {}",
+                        job_id, failed_polls, self.max_retries, error
+                    );
+                }
+                Err(error) => return Err(error),
+            }
+
+            std::thread::sleep(interval);
+            interval = options.get_next_interval(interval);
+        }
+    }
+
+    /// Asynchronously polls a batch job with an exponential backoff until it's over, and returns it.
+    ///
+    /// See [Client::wait_batch_job] for more information.
+    pub async fn wait_batch_job_async(
+        &self,
+        job_id: &str,
+        options: Option<batch::BatchJobWaitParams>,
+    ) -> Result<batch::BatchJobResponse, error::ApiError> {
+        let options = options.unwrap_or_default();
+
+        let mut interval = options.initial_interval;
+        let mut failed_polls = 0;
+        loop {
+            match self.get_batch_job_async(job_id).await {
+                Ok(job) => {
+                    failed_polls = 0;
+                    if job.status.is_terminal() {
+                        return Ok(job);
+                    }
+                    debug!(
+                        "Batch job `{}` is {:?} ({}/{} requests completed).",
+                        job.id, job.status, job.completed_requests, job.total_requests
+                    );
+                }
+                Err(error) if error.is_retryable() && failed_polls < self.max_retries => {
+                    failed_polls += 1;
+                    warn!(
+                        "Failed to poll the `{}` batch job (retry {}/{}): {}",
+                        job_id, failed_polls, self.max_retries, error
+                    );
+                }
+                Err(error) => return Err(error),
+            }
+
+            tokio::time::sleep(interval).await;
+            interval = options.get_next_interval(interval);
+        }
+    }
+
+    /// Synchronously downloads the output and error files of a batch job,
+    /// and returns the typed responses and the errors keyed by the `custom_id` of their request.
+    ///
+    /// `T` is the response type of the batch requests, e.g. [chat::ChatResponse] for chat requests.
+    pub fn get_batch_results<T: serde::de::DeserializeOwned>(
+        &self,
+        job: &batch::BatchJobResponse,
+    ) -> Result<batch::BatchResults<T>, error::ApiError> {
+        let mut contents = Vec::new();
+        for file_id in job.output_file.iter().chain(job.error_file.iter()) {
+            let mut content = Vec::new();
+            self.download_file(file_id, &mut content)?;
+            contents.push(String::from_utf8_lossy(&content).into_owned());
+        }
+
+        get_batch_results(&contents)
+    }
+
+    /// Asynchronously downloads the output and error files of a batch job,
+    /// and returns the typed responses and the errors keyed by the `custom_id` of their request.
+    ///
+    /// See [Client::get_batch_results] for more information.
+    pub async fn get_batch_results_async<T: serde::de::DeserializeOwned>(
+        &self,
+        job: &batch::BatchJobResponse,
+    ) -> Result<batch::BatchResults<T>, error::ApiError> {
+        let mut contents = Vec::new();
+        for file_id in job.output_file.iter().chain(job.error_file.iter()) {
+            let mut content = Vec::new();
+            self.download_file_async(file_id, &mut content).await?;
+            contents.push(String::from_utf8_lossy(&content).into_owned());
+        }
+
+        get_batch_results(&contents)
+    }
+
+    /// Asynchronously executes a batch job input file locally, sending its requests right away
+    /// instead of creating a batch job, and writes their results in the batch job output format.
+    ///
+    /// Each request body is validated as a [chat::ChatRequest] or an [embedding::EmbeddingRequest],
+    /// depending on the `endpoint`, and its model is resolved and checked like any other request.
+    /// The body is then sent as is (unlike [Client::chat_async], without calling the registered
+    /// functions). The requests rejected by the API rate limit are retried with an exponential backoff.
+    /// Each result is appended to the output file as soon as it's received. If the output file
+    /// already exists, the requests whose `custom_id` is already in it are skipped,
+    /// so an interrupted run can be resumed by running it again.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{
+    ///     batch::{BatchEndpoint, BatchResults},
+    ///     batch_runner::BatchRunnerParams,
+    ///     chat::ChatResponse,
+    ///     client::Client,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new(None, None, None, None).unwrap();
+    ///     let options = BatchRunnerParams {
+    ///         concurrency: 8,
+    ///         model: Some("mistral-small-latest".to_string()),
+    ///         requests_per_minute: Some(120),
+    ///         ..Default::default()
+    ///     };
+    ///     let report = client
+    ///         .run_batch_file_async("batch.jsonl", "batch_output.jsonl", BatchEndpoint::ChatCompletions, Some(options))
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{} succeeded, {} failed.", report.succeeded_requests, report.failed_requests);
+    ///
+    ///     let output = std::fs::read_to_string("batch_output.jsonl").unwrap();
+    ///     let results = BatchResults::<ChatResponse>::from_jsonl(&[&output]).unwrap();
+    /// }
+    /// ```
+    pub async fn run_batch_file_async<P: AsRef<Path>, Q: AsRef<Path>>(
+        &self,
+        input_path: P,
+        output_path: Q,
+        endpoint: batch::BatchEndpoint,
+        options: Option<batch_runner::BatchRunnerParams>,
+    ) -> Result<batch_runner::BatchRunReport, error::ApiError> {
+        let options = options.unwrap_or_default();
+        if !matches!(
+            endpoint,
+            batch::BatchEndpoint::ChatCompletions | batch::BatchEndpoint::Embeddings
+        ) {
+            return Err(get_unsupported_batch_endpoint_error(&endpoint));
+        }
+
+        let input = tokio::fs::read_to_string(input_path)
+            .await
+            .map_err(to_file_api_error)?;
+        let input_lines =
+            batch_runner::parse_batch_input(&input).map_err(|message| error::ApiError {
+                message,
+                kind: error::ApiErrorKind::Other,
+            })?;
+
+        let output_path = output_path.as_ref();
+        let previous_output = match tokio::fs::read_to_string(output_path).await {
+            Ok(content) => content,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(to_file_api_error(error)),
+        };
+        let progress = batch_runner::get_batch_output_progress(&previous_output);
+        if !progress.invalid_line_numbers.is_empty() {
+            warn!(
+                "Ignoring the invalid lines {:?} of the batch output file.",
+                progress.invalid_line_numbers
+            );
+        }
+        let mut output_file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(output_path)
+            .await
+            .map_err(to_file_api_error)?;
+        // Drops a line truncated by an interrupted run before appending the new ones.
+        output_file
+            .set_len(progress.valid_length as u64)
+            .await
+            .map_err(to_file_api_error)?;
+        output_file
+            .seek(std::io::SeekFrom::End(0))
+            .await
+            .map_err(to_file_api_error)?;
+
+        let mut report = batch_runner::BatchRunReport::default();
+        let pending_input_lines: Vec<batch_runner::BatchInputLine> = input_lines
+            .into_iter()
+            .filter(|input_line| {
+                let is_completed = progress
+                    .completed_custom_ids
+                    .contains(&input_line.custom_id);
+                if is_completed {
+                    report.skipped_requests += 1;
+                }
+
+                !is_completed
+            })
+            .collect();
+        debug!(
+            "Running {} batch requests ({} already completed).",
+            pending_input_lines.len(),
+            report.skipped_requests
+        );
+
+        let rate_limiter = batch_runner::RateLimiter::new(options.requests_per_minute);
+        let mut output_lines = futures::stream::iter(pending_input_lines)
+            .map(|input_line| async {
+                rate_limiter.wait().await;
+
+                self.run_batch_line_async(&endpoint, input_line, &options)
+                    .await
+            })
+            .buffer_unordered(options.concurrency.max(1));
+        while let Some(output_line) = output_lines.next().await {
+            let is_success = output_line.error.is_none()
+                && output_line
+                    .response
+                    .as_ref()
+                    .is_some_and(|response| (200..300).contains(&response.status_code));
+            if is_success {
+                report.succeeded_requests += 1;
+            } else {
+                report.failed_requests += 1;
+            }
+
+            let mut line = serde_json::to_vec(&output_line).map_err(|error| error::ApiError {
+                message: format!("Failed to serialize the batch output line: {}", error),
+                kind: error::ApiErrorKind::Serialization,
+            })?;
+            line.push(b'\n');
+            output_file
+                .write_all(&line)
+                .await
+                .map_err(to_file_api_error)?;
+            output_file.flush().await.map_err(to_file_api_error)?;
+        }
+
+        Ok(report)
+    }
+
+    /// Synchronously creates a fine-tuning job.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The base [Model] to fine-tune.
+    /// * `training_files` - The uploaded training files (see [Client::upload_file]).
+    /// * `options` - Optional [FineTuningJobParams] to customize the job, e.g. its hyperparameters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use mistralai_client::v1::{
+    ///     client::Client,
+    ///     constants::Model,
+    ///     fine_tuning::{FineTuningHyperparameters, FineTuningJobParams, FineTuningTrainingFile},
+    /// };
+    ///
+    /// let client = Client::new(None, None, None, None).unwrap();
+    /// let options = FineTuningJobParams {
+    ///     hyperparameters: Some(FineTuningHyperparameters {
+    ///         training_steps: Some(10),
+    ///         learning_rate: Some(0.0001),
+    ///         ..Default::default()
+    ///     }),
+    ///     suffix: Some("support-bot".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let job = client
+    ///     .create_fine_tuning_job(
+    ///         Model::OpenMistral7b,
+    ///         vec![FineTuningTrainingFile::new("training_file_id")],
+    ///         Some(options),
+    ///     )
+    ///     .unwrap();
+    /// println!("{}: {:?}", job.id, job.status);
+    /// ```
+    pub fn create_fine_tuning_job(
+        &self,
+        model: impl Into<constants::Model>,
+        training_files: Vec<fine_tuning::FineTuningTrainingFile>,
+        options: Option<fine_tuning::FineTuningJobParams>,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let request = fine_tuning::FineTuningJobRequest::new(model.into(), training_files, options);
+        let response =
+            self.post_sync("/fine_tuning/jobs", Some(request.model.as_str()), &request)?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously creates a fine-tuning job.
+    ///
+    /// See [Client::create_fine_tuning_job] for more information.
+    pub async fn create_fine_tuning_job_async(
+        &self,
+        model: impl Into<constants::Model>,
+        training_files: Vec<fine_tuning::FineTuningTrainingFile>,
+        options: Option<fine_tuning::FineTuningJobParams>,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let request = fine_tuning::FineTuningJobRequest::new(model.into(), training_files, options);
+        let response = self
+            .post_async("/fine_tuning/jobs", Some(request.model.as_str()), &request)
+            .await?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously lists the fine-tuning jobs, by page.
+    pub fn list_fine_tuning_jobs(
+        &self,
+        options: Option<fine_tuning::FineTuningJobListParams>,
+    ) -> Result<fine_tuning::FineTuningJobListResponse, error::ApiError> {
+        let request = fine_tuning::FineTuningJobListRequest::new(options);
+        let response = self.get_sync(&format!(
+            "/fine_tuning/jobs{}",
+            utils::get_query_string(&request)
+        ))?;
+        let result = response.json::<fine_tuning::FineTuningJobListResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously lists the fine-tuning jobs, by page.
+    pub async fn list_fine_tuning_jobs_async(
+        &self,
+        options: Option<fine_tuning::FineTuningJobListParams>,
+    ) -> Result<fine_tuning::FineTuningJobListResponse, error::ApiError> {
+        let request = fine_tuning::FineTuningJobListRequest::new(options);
+        let response = self
+            .get_async(&format!(
+                "/fine_tuning/jobs{}",
+                utils::get_query_string(&request)
+            ))
+            .await?;
+        let result = response
+            .json::<fine_tuning::FineTuningJobListResponse>()
+            .await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously retrieves a fine-tuning job, including its events and checkpoints.
+    pub fn get_fine_tuning_job(
+        &self,
+        job_id: &str,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let response = self.get_sync(&format!("/fine_tuning/jobs/{}", job_id))?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously retrieves a fine-tuning job, including its events and checkpoints.
+    pub async fn get_fine_tuning_job_async(
+        &self,
+        job_id: &str,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let response = self
+            .get_async(&format!("/fine_tuning/jobs/{}", job_id))
+            .await?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously requests the cancellation of a fine-tuning job.
+    pub fn cancel_fine_tuning_job(
+        &self,
+        job_id: &str,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let response = self.post_sync(
+            &format!("/fine_tuning/jobs/{}/cancel", job_id),
+            None,
+            &serde_json::json!({}),
+        )?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously requests the cancellation of a fine-tuning job.
+    pub async fn cancel_fine_tuning_job_async(
+        &self,
+        job_id: &str,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let response = self
+            .post_async(
+                &format!("/fine_tuning/jobs/{}/cancel", job_id),
+                None,
+                &serde_json::json!({}),
+            )
+            .await?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously starts a validated fine-tuning job created without `auto_start`.
+    pub fn start_fine_tuning_job(
+        &self,
+        job_id: &str,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let response = self.post_sync(
+            &format!("/fine_tuning/jobs/{}/start", job_id),
+            None,
+            &serde_json::json!({}),
+        )?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously starts a validated fine-tuning job created without `auto_start`.
+    pub async fn start_fine_tuning_job_async(
+        &self,
+        job_id: &str,
+    ) -> Result<fine_tuning::FineTuningJobResponse, error::ApiError> {
+        let response = self
+            .post_async(
+                &format!("/fine_tuning/jobs/{}/start", job_id),
+                None,
+                &serde_json::json!({}),
+            )
+            .await?;
+        let result = response.json::<fine_tuning::FineTuningJobResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Polls a fine-tuning job with an exponential backoff, yielding the job each time its status
+    /// changes (starting with its current status), until it's over or waits to be started.
+    ///
+    /// Retryable poll errors (see [ApiError::is_retryable](error::ApiError::is_retryable)) are
+    /// retried with the same backoff, up to [Client::max_retries] times in a row.
+    /// The stream ends after yielding any other error.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::stream::StreamExt;
+    /// use mistralai_client::v1::client::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new(None, None, None, None).unwrap();
+    ///     let mut job_stream = Box::pin(client.watch_fine_tuning_job("job_id", None));
+    ///     while let Some(job_result) = job_stream.next().await {
+    ///         match job_result {
+    ///             Ok(job) => println!("{:?}", job.status),
+    ///             Err(error) => eprintln!("{}", error),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn watch_fine_tuning_job(
+        &self,
+        job_id: &str,
+        options: Option<fine_tuning::FineTuningJobWatchParams>,
+    ) -> impl Stream<Item = Result<fine_tuning::FineTuningJobResponse, error::ApiError>> + '_ {
+        let job_id = job_id.to_string();
+        let options = options.unwrap_or_default();
+
+        async_stream::stream! {
+            let mut last_status: Option<fine_tuning::FineTuningJobStatus> = None;
+            let mut interval = options.initial_interval;
+            let mut failed_polls = 0;
+            loop {
+                match self.get_fine_tuning_job_async(&job_id).await {
+                    Ok(job) => {
+                        failed_polls = 0;
+                        let is_settled = job.is_settled();
+                        if last_status.as_ref() != Some(&job.status) {
+                            last_status = Some(job.status.clone());
+                            interval = options.initial_interval;
+
+                            yield Ok(job);
+                        } else {
+                            interval = options.get_next_interval(interval);
+                        }
+
+                        if is_settled {
+                            break;
+                        }
+                    }
+                    Err(error) if error.is_retryable() && failed_polls < self.max_retries => {
+                        failed_polls += 1;
+                        warn!(
+                            "Failed to poll the `{}` fine-tuning job (retry {}/{}): {}",
+                            job_id, failed_polls, self.max_retries, error
+                        );
+                        interval = options.get_next_interval(interval);
+                    }
+                    Err(error) => {
+                        yield Err(error);
+
+                        break;
+                    }
+                }
+
+                tokio::time::sleep(interval).await;
+            }
+        }
+    }
+
+    /// Synchronously retrieves a model by its ID (or alias).
+    pub fn get_model(&self, model_id: &str) -> Result<model_list::ModelListData, error::ApiError> {
+        let response = self.get_sync(&format!("/models/{}", model_id))?;
+        let result = response.json::<model_list::ModelListData>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously retrieves a model by its ID (or alias).
+    pub async fn get_model_async(
+        &self,
+        model_id: &str,
+    ) -> Result<model_list::ModelListData, error::ApiError> {
+        let response = self.get_async(&format!("/models/{}", model_id)).await?;
+        let result = response.json::<model_list::ModelListData>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously deletes a fine-tuned model.
+    pub fn delete_model(
+        &self,
+        model_id: &str,
+    ) -> Result<model::ModelDeleteResponse, error::ApiError> {
+        let response = self.delete_sync(&format!("/models/{}", model_id))?;
+        let result = response.json::<model::ModelDeleteResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously deletes a fine-tuned model.
+    pub async fn delete_model_async(
+        &self,
+        model_id: &str,
+    ) -> Result<model::ModelDeleteResponse, error::ApiError> {
+        let response = self.delete_async(&format!("/models/{}", model_id)).await?;
+        let result = response.json::<model::ModelDeleteResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously updates the name and/or description of a fine-tuned model.
+    pub fn update_model(
+        &self,
+        model_id: &str,
+        options: model::ModelUpdateParams,
+    ) -> Result<model_list::ModelListData, error::ApiError> {
+        let request = model::ModelUpdateRequest::new(options);
+        let response = self.patch_sync(&format!("/fine_tuning/models/{}", model_id), &request)?;
+        let result = response.json::<model_list::ModelListData>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously updates the name and/or description of a fine-tuned model.
+    pub async fn update_model_async(
+        &self,
+        model_id: &str,
+        options: model::ModelUpdateParams,
+    ) -> Result<model_list::ModelListData, error::ApiError> {
+        let request = model::ModelUpdateRequest::new(options);
+        let response = self
+            .patch_async(&format!("/fine_tuning/models/{}", model_id), &request)
+            .await?;
+        let result = response.json::<model_list::ModelListData>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously archives a fine-tuned model.
+    pub fn archive_model(
+        &self,
+        model_id: &str,
+    ) -> Result<model::ModelArchiveResponse, error::ApiError> {
+        let response = self.post_sync(
+            &format!("/fine_tuning/models/{}/archive", model_id),
+            None,
+            &serde_json::json!({}),
+        )?;
+        let result = response.json::<model::ModelArchiveResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously archives a fine-tuned model.
+    pub async fn archive_model_async(
+        &self,
+        model_id: &str,
+    ) -> Result<model::ModelArchiveResponse, error::ApiError> {
+        let response = self
+            .post_async(
+                &format!("/fine_tuning/models/{}/archive", model_id),
+                None,
+                &serde_json::json!({}),
+            )
+            .await?;
+        let result = response.json::<model::ModelArchiveResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously unarchives a fine-tuned model.
+    pub fn unarchive_model(
+        &self,
+        model_id: &str,
+    ) -> Result<model::ModelArchiveResponse, error::ApiError> {
+        let response = self.delete_sync(&format!("/fine_tuning/models/{}/archive", model_id))?;
+        let result = response.json::<model::ModelArchiveResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Asynchronously unarchives a fine-tuned model.
+    pub async fn unarchive_model_async(
+        &self,
+        model_id: &str,
+    ) -> Result<model::ModelArchiveResponse, error::ApiError> {
+        let response = self
+            .delete_async(&format!("/fine_tuning/models/{}/archive", model_id))
+            .await?;
+        let result = response.json::<model::ModelArchiveResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    pub fn list_models(&self) -> Result<model_list::ModelListResponse, error::ApiError> {
+        let response = self.get_sync("/models")?;
+        let result = response.json::<model_list::ModelListResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    pub async fn list_models_async(
+        &self,
+    ) -> Result<model_list::ModelListResponse, error::ApiError> {
+        let response = self.get_async("/models").await?;
+        let result = response.json::<model_list::ModelListResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    /// Synchronously fetches the capabilities of all the models available to the API key.
+    pub fn fetch_model_registry(&self) -> Result<model_registry::ModelRegistry, error::ApiError> {
+        let model_list = self.list_models()?;
+
+        Ok(model_registry::ModelRegistry::from_model_list(&model_list))
+    }
+
+    /// Asynchronously fetches the capabilities of all the models available to the API key.
+    pub async fn fetch_model_registry_async(
+        &self,
+    ) -> Result<model_registry::ModelRegistry, error::ApiError> {
+        let model_list = self.list_models_async().await?;
+
+        Ok(model_registry::ModelRegistry::from_model_list(&model_list))
+    }
+
+    /// Enables the client-side model capability checks, rejecting the chat and FIM requests using
+    /// a feature their model doesn't support (e.g. tools) before sending them.
+    ///
+    /// Set it to `None` to disable the checks (default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mistralai_client::v1::{client::Client, model_registry::ModelRegistry};
+    ///
+    /// let mut client = Client::new(Some("your_api_key_here".to_string()), None, None, None).unwrap();
+    /// // Or `client.fetch_model_registry().ok()` to use the up-to-date capabilities.
+    /// client.set_model_registry(Some(ModelRegistry::default()));
+    /// ```
+    pub fn set_model_registry(&mut self, model_registry: Option<model_registry::ModelRegistry>) {
+        self.model_registry = model_registry;
+    }
+
+    /// Enables the resolution of `-latest` model aliases (e.g. [Model::MistralLargeLatest]) to the
+    /// concrete model version they currently point to, before sending chat, FIM and embeddings requests.
+    ///
+    /// The concrete versions are read from the models listing, fetched on demand and cached for
+    /// `ttl`. Responses then report the concrete model used. A `log` warning is also emitted, once
+    /// per listing fetch, for each requested model whose deprecation date is near or past.
+    ///
+    /// Set it to `None` to disable the resolution (default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mistralai_client::v1::{client::Client, model_registry::ModelResolutionParams};
+    ///
+    /// let mut client = Client::new(Some("your_api_key_here".to_string()), None, None, None).unwrap();
+    /// client.set_model_resolution(Some(ModelResolutionParams::default()));
+    /// ```
+    pub fn set_model_resolution(
+        &mut self,
+        model_resolution: Option<model_registry::ModelResolutionParams>,
+    ) {
+        self.model_resolution = model_resolution;
+
+        let mut cache = self.model_registry_cache.lock().unwrap();
+        *cache = None;
+    }
+
+    pub fn register_function(&mut self, name: String, function: Box<dyn tool::Function>) {
+        let mut functions = self.functions.lock().unwrap();
+
+        functions.insert(name, Arc::from(function));
+    }
+
+    /// Sends the requests matching the `route` to another endpoint, with its own API key.
+    ///
+    /// Model routes take precedence over path routes, and later overrides over earlier ones.
+    /// Requests matching no route are sent to the client default `endpoint` with its `api_key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mistralai_client::v1::client::{Client, ClientEndpoint, ClientRoute};
+    ///
+    /// let mut client = Client::new(Some("api_key".to_string()), None, None, None).unwrap();
+    /// client.set_endpoint_override(
+    ///     ClientRoute::Model("ft:open-mistral-7b:1234abcd:20240101:5678efgh".to_string()),
+    ///     ClientEndpoint {
+    ///         url: "https://llm.internal.example.org/v1".to_string(),
+    ///         api_key: "another_api_key".to_string(),
+    ///     },
+    /// );
+    /// ```
+    pub fn set_endpoint_override(&mut self, route: ClientRoute, endpoint: ClientEndpoint) {
+        self.endpoint_overrides
+            .retain(|(existing_route, _)| existing_route != &route);
+        self.endpoint_overrides.push((route, endpoint));
+    }
+
+    /// Sends the FIM and `codestral-latest` requests to the dedicated Codestral endpoint,
+    /// using its own API key.
+    ///
+    /// See [Client::set_endpoint_override] for more information.
+    pub fn set_codestral_endpoint(&mut self, codestral_api_key: String) {
+        let endpoint = ClientEndpoint {
+            url: constants::CODESTRAL_API_URL_BASE.to_string(),
+            api_key: codestral_api_key,
+        };
+
+        self.set_endpoint_override(ClientRoute::Path("/fim".to_string()), endpoint.clone());
+        self.set_endpoint_override(ClientRoute::Model("codestral-latest".to_string()), endpoint);
+    }
+
+    /// Returns the endpoint URL and API key of a request, applying the matching override, if any.
+    pub fn get_endpoint(&self, path: &str, model_id: Option<&str>) -> (&str, &str) {
+        let model_override = self.endpoint_overrides.iter().rev().find(|(route, _)| {
+            matches!(route, ClientRoute::Model(route_model_id) if Some(route_model_id.as_str()) == model_id)
+        });
+        let path_override = self.endpoint_overrides.iter().rev().find(|(route, _)| {
+            matches!(route, ClientRoute::Path(route_path) if path.starts_with(route_path.as_str()))
+        });
+
+        match model_override.or(path_override) {
+            Some((_, endpoint)) => (&endpoint.url, &endpoint.api_key),
+            None => (&self.endpoint, &self.api_key),
+        }
+    }
+
+    fn build_request_sync(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+        api_key: &str,
+    ) -> reqwest::blocking::RequestBuilder {
+        let user_agent = format!(
+            "ivangabriele/mistralai-client-rs/{}",
+            env!("CARGO_PKG_VERSION")
+        );
+
+        request
+            .bearer_auth(api_key)
+            .header("Accept", "application/json")
+            .header("User-Agent", user_agent)
+    }
+
+    fn build_request_async(
+        &self,
+        request: reqwest::RequestBuilder,
+        api_key: &str,
+    ) -> reqwest::RequestBuilder {
+        let user_agent = format!(
+            "ivangabriele/mistralai-client-rs/{}",
+            env!("CARGO_PKG_VERSION")
+        );
+
+        request
+            .bearer_auth(api_key)
+            .header("Accept", "application/json")
+            .header("User-Agent", user_agent)
+    }
+
+    fn build_request_download_sync(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+        api_key: &str,
+    ) -> reqwest::blocking::RequestBuilder {
+        let user_agent = format!(
+            "ivangabriele/mistralai-client-rs/{}",
+            env!("CARGO_PKG_VERSION")
+        );
+
+        request
+            .bearer_auth(api_key)
+            .header("Accept", "application/octet-stream")
+            .header("User-Agent", user_agent)
+    }
+
+    fn build_request_download_async(
+        &self,
+        request: reqwest::RequestBuilder,
+        api_key: &str,
+    ) -> reqwest::RequestBuilder {
+        let user_agent = format!(
+            "ivangabriele/mistralai-client-rs/{}",
+            env!("CARGO_PKG_VERSION")
+        );
+
+        request
+            .bearer_auth(api_key)
+            .header("Accept", "application/octet-stream")
+            .header("User-Agent", user_agent)
+    }
+
+    fn build_request_stream(
+        &self,
+        request: reqwest::RequestBuilder,
+        api_key: &str,
+    ) -> reqwest::RequestBuilder {
+        let user_agent = format!(
+            "ivangabriele/mistralai-client-rs/{}",
             env!("CARGO_PKG_VERSION")
         );
 
-        let request_builder = request
-            .bearer_auth(&self.api_key)
-            .header("Accept", "text/event-stream")
-            .header("Content-Type", "application/json")
-            .header("User-Agent", user_agent);
+        request
+            .bearer_auth(api_key)
+            .header("Accept", "text/event-stream")
+            .header("Content-Type", "application/json")
+            .header("User-Agent", user_agent)
+    }
+
+    /// Resolves a model alias into its current model ID, when the model resolution is enabled.
+    ///
+    /// The request must still be routed with the requested model, since endpoint overrides
+    /// target aliases (e.g. `codestral-latest`) rather than the model IDs they resolve to.
+    fn resolve_model_sync(&self, model: constants::Model) -> constants::Model {
+        constants::Model::new(&self.resolve_model_id_sync(model.as_str()))
+    }
+
+    async fn resolve_model_async(&self, model: constants::Model) -> constants::Model {
+        constants::Model::new(&self.resolve_model_id_async(model.as_str()).await)
+    }
+
+    /// Sends a batch input line request, retrying it while rate-limited, and returns its batch output line.
+    async fn run_batch_line_async(
+        &self,
+        endpoint: &batch::BatchEndpoint,
+        input_line: batch_runner::BatchInputLine,
+        options: &batch_runner::BatchRunnerParams,
+    ) -> batch::BatchOutputLine {
+        let batch_runner::BatchInputLine {
+            custom_id,
+            mut body,
+        } = input_line;
+        if let (Some(object), Some(model_id)) = (body.as_object_mut(), &options.model) {
+            object
+                .entry("model")
+                .or_insert_with(|| serde_json::Value::String(model_id.clone()));
+        }
+
+        let mut interval = options.rate_limit_initial_interval;
+        let mut rate_limited_attempts = 0;
+        let result = loop {
+            match self
+                .send_batch_line_body_async(endpoint, body.clone())
+                .await
+            {
+                Err(error)
+                    if error.status_code() == Some(429)
+                        && rate_limited_attempts < self.max_retries =>
+                {
+                    rate_limited_attempts += 1;
+                    warn!(
+                        "Rate-limited batch request `{}` (retry {}/{}).",
+                        custom_id, rate_limited_attempts, self.max_retries
+                    );
+                    tokio::time::sleep(interval).await;
+                    interval = options.get_next_rate_limit_interval(interval);
+                }
+                result => break result,
+            }
+        };
+
+        let (response, error) = match result {
+            Ok(body) => (
+                Some(batch::BatchOutputLineResponse {
+                    status_code: 200,
+                    body,
+                }),
+                None,
+            ),
+            Err(error) => match error.status_code() {
+                Some(status_code) => (
+                    Some(batch::BatchOutputLineResponse {
+                        status_code,
+                        body: serde_json::json!({ "message": error.message }),
+                    }),
+                    None,
+                ),
+                None => (None, Some(serde_json::json!({ "message": error.message }))),
+            },
+        };
+
+        batch::BatchOutputLine {
+            id: None,
+            custom_id,
+            response,
+            error,
+            extra: HashMap::new(),
+        }
+    }
+
+    /// Validates a batch input line request body for the `endpoint`, sends it (with its model resolved)
+    /// and returns the response body.
+    ///
+    /// The body itself is sent rather than the parsed request, so that the fields unknown to this
+    /// client version are kept, and the registered functions aren't called.
+    async fn send_batch_line_body_async(
+        &self,
+        endpoint: &batch::BatchEndpoint,
+        mut body: serde_json::Value,
+    ) -> Result<serde_json::Value, error::ApiError> {
+        let to_body_error = |error: serde_json::Error| error::ApiError {
+            message: format!("Invalid batch request body: {}", error),
+            kind: error::ApiErrorKind::Serialization,
+        };
+
+        let response = match endpoint {
+            batch::BatchEndpoint::ChatCompletions => {
+                let request = serde_json::from_value::<chat::ChatRequest>(body.clone())
+                    .map_err(to_body_error)?;
+                let requested_model = request.model.clone();
+                let request = chat::ChatRequest {
+                    model: self.resolve_model_async(request.model).await,
+                    ..request
+                };
+                self.check_chat_request(&request)?;
+                body["model"] = serde_json::Value::String(request.model.to_string());
+
+                let response = self
+                    .post_async("/chat/completions", Some(requested_model.as_str()), &body)
+                    .await?;
+                let data = response
+                    .json::<chat::ChatResponse>()
+                    .await
+                    .map_err(|error| self.to_api_error(error))?;
+
+                serde_json::to_value(data)
+            }
+            batch::BatchEndpoint::Embeddings => {
+                let request = serde_json::from_value::<embedding::EmbeddingRequest>(body.clone())
+                    .map_err(to_body_error)?;
+                let model_id = self.resolve_model_id_async(request.model.as_str()).await;
+                body["model"] = serde_json::Value::String(model_id);
+
+                let response = self
+                    .post_async("/embeddings", Some(request.model.as_str()), &body)
+                    .await?;
+                let data = response
+                    .json::<embedding::EmbeddingResponse>()
+                    .await
+                    .map_err(|error| self.to_api_error(error))?;
+
+                serde_json::to_value(data)
+            }
+            _ => return Err(get_unsupported_batch_endpoint_error(endpoint)),
+        };
+
+        response.map_err(|error| error::ApiError {
+            message: format!("Failed to serialize the batch response: {}", error),
+            kind: error::ApiErrorKind::Serialization,
+        })
+    }
+
+    fn resolve_model_id_sync(&self, model_id: &str) -> String {
+        let model_resolution = match &self.model_resolution {
+            Some(model_resolution) => model_resolution,
+            None => return model_id.to_string(),
+        };
+
+        if self.is_model_registry_cache_expired(model_resolution) {
+            // The other callers needing a refresh wait for this one instead of fetching too,
+            // while the callers with a fresh cache aren't blocked by the fetch.
+            let _refresh_guard = self.model_registry_refresh_sync.lock().unwrap();
+            if self.is_model_registry_cache_expired(model_resolution) {
+                let registry = self.fetch_model_registry();
+                self.set_model_registry_cache(registry);
+            }
+        }
+
+        self.resolve_cached_model_id(model_id, model_resolution)
+    }
+
+    async fn resolve_model_id_async(&self, model_id: &str) -> String {
+        let model_resolution = match &self.model_resolution {
+            Some(model_resolution) => model_resolution,
+            None => return model_id.to_string(),
+        };
+
+        if self.is_model_registry_cache_expired(model_resolution) {
+            // See `resolve_model_id_sync()`.
+            let _refresh_guard = self.model_registry_refresh.lock().await;
+            if self.is_model_registry_cache_expired(model_resolution) {
+                let registry = self.fetch_model_registry_async().await;
+                self.set_model_registry_cache(registry);
+            }
+        }
+
+        self.resolve_cached_model_id(model_id, model_resolution)
+    }
+
+    fn is_model_registry_cache_expired(
+        &self,
+        model_resolution: &model_registry::ModelResolutionParams,
+    ) -> bool {
+        self.model_registry_cache
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map_or(true, |cache| cache.is_expired(model_resolution.ttl))
+    }
+
+    fn set_model_registry_cache(
+        &self,
+        registry: Result<model_registry::ModelRegistry, error::ApiError>,
+    ) {
+        match registry {
+            Ok(registry) => {
+                let mut cache = self.model_registry_cache.lock().unwrap();
+                *cache = Some(model_registry::ModelRegistryCache::new(registry));
+            }
+            // A stale listing is still better than none when the fetch fails.
+            Err(error) => warn!("Failed to fetch the models to resolve aliases: {}", error),
+        }
+    }
+
+    fn resolve_cached_model_id(
+        &self,
+        model_id: &str,
+        model_resolution: &model_registry::ModelResolutionParams,
+    ) -> String {
+        let mut cache = self.model_registry_cache.lock().unwrap();
+        match cache.as_mut() {
+            Some(cache) => cache.resolve(model_id, model_resolution),
+            None => model_id.to_string(),
+        }
+    }
+
+    fn check_chat_request(&self, request: &chat::ChatRequest) -> Result<(), error::ApiError> {
+        match &self.model_registry {
+            Some(model_registry) => {
+                model_registry
+                    .check_chat_request(request)
+                    .map_err(|error| error::ApiError {
+                        message: error.to_string(),
+                        kind: error::ApiErrorKind::Other,
+                    })
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn check_fim_request(&self, request: &fim::FimRequest) -> Result<(), error::ApiError> {
+        match &self.model_registry {
+            Some(model_registry) => {
+                model_registry
+                    .check_fim_request(request)
+                    .map_err(|error| error::ApiError {
+                        message: error.to_string(),
+                        kind: error::ApiErrorKind::Other,
+                    })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Sends a chat completion request like [Client::chat], without calling the registered functions.
+    fn chat_without_functions(
+        &self,
+        requested_model: constants::Model,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+    ) -> Result<chat::ChatResponse, error::ApiError> {
+        let model = self.resolve_model_sync(requested_model.clone());
+        let request = chat::ChatRequest::new(model, messages, false, options);
+        self.check_chat_request(&request)?;
+
+        let response = self.post_sync(
+            "/chat/completions",
+            Some(requested_model.as_str()),
+            &request,
+        )?;
+        let result = response.json::<chat::ChatResponse>();
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
+
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
+    }
+
+    async fn chat_without_functions_async(
+        &self,
+        requested_model: constants::Model,
+        messages: Vec<chat::ChatMessage>,
+        options: Option<chat::ChatParams>,
+    ) -> Result<chat::ChatResponse, error::ApiError> {
+        let model = self.resolve_model_async(requested_model.clone()).await;
+        let request = chat::ChatRequest::new(model, messages, false, options);
+        self.check_chat_request(&request)?;
+
+        let response = self
+            .post_async(
+                "/chat/completions",
+                Some(requested_model.as_str()),
+                &request,
+            )
+            .await?;
+        let result = response.json::<chat::ChatResponse>().await;
+        match result {
+            Ok(data) => {
+                utils::debug_pretty_json_from_struct("Response Data", &data);
 
-        request_builder
+                Ok(data)
+            }
+            Err(error) => Err(self.to_api_error(error)),
+        }
     }
 
-    fn call_function_if_any(&self, response: chat::ChatResponse) -> () {
-        let next_result = match response.choices.get(0) {
-            Some(first_choice) => match first_choice.message.tool_calls.to_owned() {
-                Some(tool_calls) => match tool_calls.get(0) {
+    fn call_function_if_any(&self, response: chat::ChatResponse) {
+        // With multiple choices, the first one calling a function wins.
+        let calling_choice = response
+            .choices
+            .iter()
+            .find(|choice| choice.message.tool_calls.is_some())
+            .or(response.choices.first());
+        let next_result = match calling_choice {
+            Some(choice) => match choice.message.tool_calls.to_owned() {
+                Some(tool_calls) => match tool_calls.first() {
                     Some(first_tool_call) => {
-                        let functions = self.functions.lock().unwrap();
-                        match functions.get(&first_tool_call.function.name) {
+                        // See `call_function_if_any_async()`.
+                        let function = self
+                            .functions
+                            .lock()
+                            .unwrap()
+                            .get(&first_tool_call.function.name)
+                            .cloned();
+                        match function {
                             Some(function) => {
                                 let runtime = tokio::runtime::Runtime::new().unwrap();
                                 let result = runtime.block_on(async {
@@ -451,13 +2907,25 @@ impl Client {
         *last_result_lock = next_result;
     }
 
-    async fn call_function_if_any_async(&self, response: chat::ChatResponse) -> () {
-        let next_result = match response.choices.get(0) {
-            Some(first_choice) => match first_choice.message.tool_calls.to_owned() {
-                Some(tool_calls) => match tool_calls.get(0) {
+    async fn call_function_if_any_async(&self, response: chat::ChatResponse) {
+        // With multiple choices, the first one calling a function wins.
+        let calling_choice = response
+            .choices
+            .iter()
+            .find(|choice| choice.message.tool_calls.is_some())
+            .or(response.choices.first());
+        let next_result = match calling_choice {
+            Some(choice) => match choice.message.tool_calls.to_owned() {
+                Some(tool_calls) => match tool_calls.first() {
                     Some(first_tool_call) => {
-                        let functions = self.functions.lock().unwrap();
-                        match functions.get(&first_tool_call.function.name) {
+                        // The lock must not be held while the function runs.
+                        let function = self
+                            .functions
+                            .lock()
+                            .unwrap()
+                            .get(&first_tool_call.function.name)
+                            .cloned();
+                        match function {
                             Some(function) => {
                                 let result = function
                                     .execute(first_tool_call.function.arguments.to_owned())
@@ -481,146 +2949,223 @@ impl Client {
 
     fn get_sync(&self, path: &str) -> Result<reqwest::blocking::Response, error::ApiError> {
         let reqwest_client = reqwest::blocking::Client::new();
-        let url = format!("{}{}", self.endpoint, path);
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
         debug!("Request URL: {}", url);
 
-        let request = self.build_request_sync(reqwest_client.get(url));
-
-        let result = request.send();
-        match result {
-            Ok(response) => {
-                if response.status().is_success() {
-                    Ok(response)
-                } else {
-                    let response_status = response.status();
-                    let response_body = response.text().unwrap_or_default();
-                    debug!("Response Status: {}", &response_status);
-                    utils::debug_pretty_json_from_string("Response Data", &response_body);
+        let request = self.build_request_sync(reqwest_client.get(url), api_key);
 
-                    Err(error::ApiError {
-                        message: format!("{}: {}", response_status, response_body),
-                    })
-                }
-            }
-            Err(error) => Err(error::ApiError {
-                message: error.to_string(),
-            }),
-        }
+        self.send_sync(request)
     }
 
     async fn get_async(&self, path: &str) -> Result<reqwest::Response, error::ApiError> {
         let reqwest_client = reqwest::Client::new();
-        let url = format!("{}{}", self.endpoint, path);
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
         debug!("Request URL: {}", url);
 
         let request_builder = reqwest_client.get(url);
-        let request = self.build_request_async(request_builder);
+        let request = self.build_request_async(request_builder, api_key);
 
-        let result = request.send().await;
-        match result {
-            Ok(response) => {
-                if response.status().is_success() {
-                    Ok(response)
-                } else {
-                    let response_status = response.status();
-                    let response_body = response.text().await.unwrap_or_default();
-                    debug!("Response Status: {}", &response_status);
-                    utils::debug_pretty_json_from_string("Response Data", &response_body);
+        self.send_async(request).await
+    }
 
-                    Err(error::ApiError {
-                        message: format!("{}: {}", response_status, response_body),
-                    })
-                }
-            }
-            Err(error) => Err(error::ApiError {
-                message: error.to_string(),
-            }),
-        }
+    fn get_download_sync(
+        &self,
+        path: &str,
+    ) -> Result<reqwest::blocking::Response, error::ApiError> {
+        let reqwest_client = reqwest::blocking::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+
+        let request = self.build_request_download_sync(reqwest_client.get(url), api_key);
+
+        self.send_sync(request)
+    }
+
+    async fn get_download_async(&self, path: &str) -> Result<reqwest::Response, error::ApiError> {
+        let reqwest_client = reqwest::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+
+        let request = self.build_request_download_async(reqwest_client.get(url), api_key);
+
+        self.send_async(request).await
+    }
+
+    fn delete_sync(&self, path: &str) -> Result<reqwest::blocking::Response, error::ApiError> {
+        let reqwest_client = reqwest::blocking::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+
+        let request = self.build_request_sync(reqwest_client.delete(url), api_key);
+
+        self.send_sync(request)
+    }
+
+    async fn delete_async(&self, path: &str) -> Result<reqwest::Response, error::ApiError> {
+        let reqwest_client = reqwest::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+
+        let request = self.build_request_async(reqwest_client.delete(url), api_key);
+
+        self.send_async(request).await
+    }
+
+    fn patch_sync<T: std::fmt::Debug + serde::ser::Serialize>(
+        &self,
+        path: &str,
+        params: &T,
+    ) -> Result<reqwest::blocking::Response, error::ApiError> {
+        let reqwest_client = reqwest::blocking::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+        utils::debug_pretty_json_from_struct("Request Body", params);
+
+        let request_builder = reqwest_client.patch(url).json(params);
+        let request = self.build_request_sync(request_builder, api_key);
+
+        self.send_sync(request)
+    }
+
+    async fn patch_async<T: serde::ser::Serialize + std::fmt::Debug>(
+        &self,
+        path: &str,
+        params: &T,
+    ) -> Result<reqwest::Response, error::ApiError> {
+        let reqwest_client = reqwest::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+        utils::debug_pretty_json_from_struct("Request Body", params);
+
+        let request_builder = reqwest_client.patch(url).json(params);
+        let request = self.build_request_async(request_builder, api_key);
+
+        self.send_async(request).await
     }
 
     fn post_sync<T: std::fmt::Debug + serde::ser::Serialize>(
         &self,
         path: &str,
+        model_id: Option<&str>,
         params: &T,
     ) -> Result<reqwest::blocking::Response, error::ApiError> {
         let reqwest_client = reqwest::blocking::Client::new();
-        let url = format!("{}{}", self.endpoint, path);
+        let (endpoint, api_key) = self.get_endpoint(path, model_id);
+        let url = format!("{}{}", endpoint, path);
         debug!("Request URL: {}", url);
         utils::debug_pretty_json_from_struct("Request Body", params);
 
         let request_builder = reqwest_client.post(url).json(params);
-        let request = self.build_request_sync(request_builder);
-
-        let result = request.send();
-        match result {
-            Ok(response) => {
-                if response.status().is_success() {
-                    Ok(response)
-                } else {
-                    let response_status = response.status();
-                    let response_body = response.text().unwrap_or_default();
-                    debug!("Response Status: {}", &response_status);
-                    utils::debug_pretty_json_from_string("Response Data", &response_body);
+        let request = self.build_request_sync(request_builder, api_key);
 
-                    Err(error::ApiError {
-                        message: format!("{}: {}", response_body, response_status),
-                    })
-                }
-            }
-            Err(error) => Err(error::ApiError {
-                message: error.to_string(),
-            }),
-        }
+        self.send_sync(request)
     }
 
     async fn post_async<T: serde::ser::Serialize + std::fmt::Debug>(
         &self,
         path: &str,
+        model_id: Option<&str>,
         params: &T,
     ) -> Result<reqwest::Response, error::ApiError> {
         let reqwest_client = reqwest::Client::new();
-        let url = format!("{}{}", self.endpoint, path);
+        let (endpoint, api_key) = self.get_endpoint(path, model_id);
+        let url = format!("{}{}", endpoint, path);
         debug!("Request URL: {}", url);
         utils::debug_pretty_json_from_struct("Request Body", params);
 
         let request_builder = reqwest_client.post(url).json(params);
-        let request = self.build_request_async(request_builder);
+        let request = self.build_request_async(request_builder, api_key);
 
-        let result = request.send().await;
+        self.send_async(request).await
+    }
+
+    fn post_multipart_sync(
+        &self,
+        path: &str,
+        form: reqwest::blocking::multipart::Form,
+    ) -> Result<reqwest::blocking::Response, error::ApiError> {
+        let reqwest_client = reqwest::blocking::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+
+        let request_builder = reqwest_client.post(url).multipart(form);
+        let request = self.build_request_sync(request_builder, api_key);
+
+        self.send_sync(request)
+    }
+
+    async fn post_multipart_async(
+        &self,
+        path: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<reqwest::Response, error::ApiError> {
+        let reqwest_client = reqwest::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, None);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+
+        let request_builder = reqwest_client.post(url).multipart(form);
+        let request = self.build_request_async(request_builder, api_key);
+
+        self.send_async(request).await
+    }
+
+    async fn post_stream<T: serde::ser::Serialize + std::fmt::Debug>(
+        &self,
+        path: &str,
+        model_id: Option<&str>,
+        params: &T,
+    ) -> Result<reqwest::Response, error::ApiError> {
+        let reqwest_client = reqwest::Client::new();
+        let (endpoint, api_key) = self.get_endpoint(path, model_id);
+        let url = format!("{}{}", endpoint, path);
+        debug!("Request URL: {}", url);
+        utils::debug_pretty_json_from_struct("Request Body", params);
+
+        let request_builder = reqwest_client.post(url).json(params);
+        let request = self.build_request_stream(request_builder, api_key);
+
+        self.send_async(request).await
+    }
+
+    fn send_sync(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, error::ApiError> {
+        let result = request.send();
         match result {
             Ok(response) => {
                 if response.status().is_success() {
                     Ok(response)
                 } else {
                     let response_status = response.status();
-                    let response_body = response.text().await.unwrap_or_default();
+                    let response_body = response.text().unwrap_or_default();
                     debug!("Response Status: {}", &response_status);
                     utils::debug_pretty_json_from_string("Response Data", &response_body);
 
                     Err(error::ApiError {
                         message: format!("{}: {}", response_status, response_body),
+                        kind: error::ApiErrorKind::Status(response_status.as_u16()),
                     })
                 }
             }
-            Err(error) => Err(error::ApiError {
-                message: error.to_string(),
-            }),
+            Err(error) => Err(self.to_api_error(error)),
         }
     }
 
-    async fn post_stream<T: serde::ser::Serialize + std::fmt::Debug>(
+    async fn send_async(
         &self,
-        path: &str,
-        params: &T,
+        request: reqwest::RequestBuilder,
     ) -> Result<reqwest::Response, error::ApiError> {
-        let reqwest_client = reqwest::Client::new();
-        let url = format!("{}{}", self.endpoint, path);
-        debug!("Request URL: {}", url);
-        utils::debug_pretty_json_from_struct("Request Body", params);
-
-        let request_builder = reqwest_client.post(url).json(params);
-        let request = self.build_request_stream(request_builder);
-
         let result = request.send().await;
         match result {
             Ok(response) => {
@@ -634,18 +3179,147 @@ impl Client {
 
                     Err(error::ApiError {
                         message: format!("{}: {}", response_status, response_body),
+                        kind: error::ApiErrorKind::Status(response_status.as_u16()),
                     })
                 }
             }
-            Err(error) => Err(error::ApiError {
-                message: error.to_string(),
-            }),
+            Err(error) => Err(self.to_api_error(error)),
         }
     }
 
     fn to_api_error(&self, err: ReqwestError) -> error::ApiError {
-        error::ApiError {
-            message: err.to_string(),
+        error::ApiError::from_reqwest_error(err)
+    }
+}
+
+fn get_file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn get_unsupported_batch_endpoint_error(endpoint: &batch::BatchEndpoint) -> error::ApiError {
+    error::ApiError {
+        message: format!(
+            "The `{}` endpoint isn't supported by the batch runner.",
+            endpoint
+        ),
+        kind: error::ApiErrorKind::Other,
+    }
+}
+
+/// Returns the model ID shared by all the batch requests and the JSONL input file content.
+fn get_batch_input<T: batch::BatchRequestBody>(
+    requests: &[batch::BatchRequest<T>],
+) -> Result<(String, Vec<u8>), error::ApiError> {
+    let model_id = match requests.first() {
+        Some(request) => request.body.model_id(),
+        None => {
+            return Err(error::ApiError {
+                message: "The batch has no requests.".to_string(),
+                kind: error::ApiErrorKind::Other,
+            })
         }
+    };
+    if let Some(request) = requests
+        .iter()
+        .find(|request| request.body.model_id() != model_id)
+    {
+        return Err(error::ApiError {
+            message: format!(
+                "All the batch requests must use the same model, but `{}` uses `{}` instead of `{}`.",
+                request.custom_id,
+                request.body.model_id(),
+                model_id
+            ),
+            kind: error::ApiErrorKind::Other,
+        });
+    }
+
+    let content = batch::get_batch_input_jsonl(requests).map_err(|error| error::ApiError {
+        message: format!("Failed to serialize the batch requests: {}", error),
+        kind: error::ApiErrorKind::Serialization,
+    })?;
+
+    Ok((model_id.to_string(), content))
+}
+
+fn get_batch_results<T: serde::de::DeserializeOwned>(
+    contents: &[String],
+) -> Result<batch::BatchResults<T>, error::ApiError> {
+    let contents: Vec<&str> = contents.iter().map(String::as_str).collect();
+
+    batch::BatchResults::from_jsonl(&contents).map_err(|message| error::ApiError {
+        message,
+        kind: error::ApiErrorKind::Other,
+    })
+}
+
+fn check_moderation_input(
+    guard: &moderation::ModerationGuardParams,
+    flagged_categories: &[moderation::ModerationCategory],
+) -> Result<(), error::ModerationError> {
+    if guard.action == moderation::ModerationAction::Block && !flagged_categories.is_empty() {
+        return Err(error::ModerationError::InputBlocked {
+            categories: flagged_categories.to_vec(),
+        });
     }
+
+    Ok(())
+}
+
+/// Returns the conversations ending with each assistant message of the response.
+fn get_moderation_output_input(
+    messages: &[chat::ChatMessage],
+    response: &chat::ChatResponse,
+) -> Vec<Vec<chat::ChatMessage>> {
+    response
+        .choices
+        .iter()
+        .map(|choice| {
+            let mut conversation = messages.to_vec();
+            conversation.push(choice.message.without_thinking());
+
+            conversation
+        })
+        .collect()
+}
+
+fn get_moderated_chat_response(
+    guard: &moderation::ModerationGuardParams,
+    response: chat::ChatResponse,
+    input_flagged_categories: Vec<moderation::ModerationCategory>,
+    output_flagged_categories: Vec<moderation::ModerationCategory>,
+) -> Result<moderation::ModeratedChatResponse, error::ModerationError> {
+    if guard.action == moderation::ModerationAction::Block && !output_flagged_categories.is_empty()
+    {
+        return Err(error::ModerationError::OutputBlocked {
+            categories: output_flagged_categories,
+            response: Box::new(response),
+        });
+    }
+
+    Ok(moderation::ModeratedChatResponse {
+        response,
+        input_flagged_categories,
+        output_flagged_categories,
+    })
+}
+
+fn to_file_api_error(error: std::io::Error) -> error::ApiError {
+    error::ApiError {
+        message: format!("File error: {}", error),
+        kind: error::ApiErrorKind::Other,
+    }
+}
+
+fn with_json_schema_response_format<T: JsonSchema>(
+    options: Option<chat::ChatParams>,
+) -> chat::ChatParams {
+    let mut options = options.unwrap_or_default();
+    if options.response_format.is_none() {
+        options.response_format = Some(chat::ResponseFormat::json_schema_for::<T>(true));
+    }
+
+    options
 }
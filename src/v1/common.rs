@@ -1,8 +1,51 @@
 use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    ops::{Add, AddAssign},
+};
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
 pub struct ResponseUsage {
     pub prompt_tokens: u32,
     pub completion_tokens: u32,
     pub total_tokens: u32,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl Add for ResponseUsage {
+    type Output = Self;
+
+    /// Sums the token counts (saturating at `u32::MAX`), as well as the unknown integer fields.
+    /// The other unknown fields keep their first value.
+    fn add(self, other: Self) -> Self {
+        let mut extra = self.extra;
+        for (key, other_value) in other.extra {
+            match extra.get_mut(&key) {
+                Some(value) => {
+                    if let (Some(count), Some(other_count)) = (value.as_u64(), other_value.as_u64())
+                    {
+                        *value = count.saturating_add(other_count).into();
+                    }
+                }
+                None => {
+                    extra.insert(key, other_value);
+                }
+            }
+        }
+
+        Self {
+            prompt_tokens: self.prompt_tokens.saturating_add(other.prompt_tokens),
+            completion_tokens: self
+                .completion_tokens
+                .saturating_add(other.completion_tokens),
+            total_tokens: self.total_tokens.saturating_add(other.total_tokens),
+            extra,
+        }
+    }
+}
+impl AddAssign for ResponseUsage {
+    fn add_assign(&mut self, other: Self) {
+        *self = std::mem::take(self) + other;
+    }
 }
@@ -1,29 +1,113 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 pub const API_URL_BASE: &str = "https://api.mistral.ai/v1";
+/// The dedicated Codestral endpoint, which requires its own API key.
+pub const CODESTRAL_API_URL_BASE: &str = "https://codestral.mistral.ai/v1";
 
-#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
-pub enum Model {
-    #[serde(rename = "open-mistral-7b")]
-    OpenMistral7b,
-    #[serde(rename = "open-mixtral-8x7b")]
-    OpenMixtral8x7b,
-    #[serde(rename = "open-mixtral-8x22b")]
-    OpenMixtral8x22b,
-    #[serde(rename = "mistral-tiny")]
-    MistralTiny,
-    #[serde(rename = "mistral-small-latest")]
-    MistralSmallLatest,
-    #[serde(rename = "mistral-medium-latest")]
-    MistralMediumLatest,
-    #[serde(rename = "mistral-large-latest")]
-    MistralLargeLatest,
-    #[serde(rename = "codestral-latest")]
-    CodestralLatest,
+/// Declares a model enum from a single `Variant => "model-id"` table,
+/// generating its serde names, [Model::new]-like constructor and [Model::as_str]-like getter.
+///
+/// Equality compares the model IDs, so that an `Unknown` holding a known ID
+/// (e.g. `Unknown("codestral-latest")`) equals its named variant.
+macro_rules! define_models {
+    (
+        $(#[$meta:meta])*
+        pub enum $type:ident {
+            $($variant:ident => $id:literal,)+
+            $(#[$unknown_meta:meta])*
+            Unknown,
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        #[non_exhaustive]
+        pub enum $type {
+            $(
+                #[serde(rename = $id)]
+                $variant,
+            )+
+            $(#[$unknown_meta])*
+            #[serde(untagged)]
+            Unknown(String),
+        }
+        impl $type {
+            #[doc = concat!("Constructs a model from its ID, falling back to [", stringify!($type), "::Unknown] for non-constant IDs.")]
+            pub fn new(id: &str) -> Self {
+                match id {
+                    $($id => Self::$variant,)+
+                    _ => Self::Unknown(id.to_string()),
+                }
+            }
+
+            /// Returns the model ID, as sent to the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $id,)+
+                    Self::Unknown(id) => id,
+                }
+            }
+        }
+        impl PartialEq for $type {
+            fn eq(&self, other: &Self) -> bool {
+                self.as_str() == other.as_str()
+            }
+        }
+        impl Eq for $type {}
+        impl From<&str> for $type {
+            fn from(id: &str) -> Self {
+                Self::new(id)
+            }
+        }
+        impl From<String> for $type {
+            fn from(id: String) -> Self {
+                Self::new(&id)
+            }
+        }
+        impl fmt::Display for $type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+    };
+}
+
+define_models! {
+    /// # Examples
+    ///
+    /// ```
+    /// use mistralai_client::v1::constants::Model;
+    ///
+    /// assert_eq!(Model::new("mistral-large-latest"), Model::MistralLargeLatest);
+    /// assert_eq!(
+    ///     Model::new("ft:open-mistral-7b:587a6b29:20240514:7e773925"),
+    ///     Model::Unknown("ft:open-mistral-7b:587a6b29:20240514:7e773925".to_string())
+    /// );
+    /// assert_eq!(Model::Unknown("codestral-latest".to_string()), Model::CodestralLatest);
+    /// ```
+    pub enum Model {
+        OpenMistral7b => "open-mistral-7b",
+        OpenMixtral8x7b => "open-mixtral-8x7b",
+        OpenMixtral8x22b => "open-mixtral-8x22b",
+        MistralTiny => "mistral-tiny",
+        MistralSmallLatest => "mistral-small-latest",
+        MistralMediumLatest => "mistral-medium-latest",
+        MistralLargeLatest => "mistral-large-latest",
+        CodestralLatest => "codestral-latest",
+        MagistralSmallLatest => "magistral-small-latest",
+        MagistralMediumLatest => "magistral-medium-latest",
+        MistralModerationLatest => "mistral-moderation-latest",
+        MistralOcrLatest => "mistral-ocr-latest",
+        /// Any other model ID, e.g. a fine-tuned model (`ft:open-mistral-7b:...`), a dated model version
+        /// or a model released after this client version.
+        Unknown,
+    }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
-pub enum EmbedModel {
-    #[serde(rename = "mistral-embed")]
-    MistralEmbed,
+define_models! {
+    pub enum EmbedModel {
+        MistralEmbed => "mistral-embed",
+        /// Any other embedding model ID, e.g. a dated model version.
+        Unknown,
+    }
 }
@@ -0,0 +1,540 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    fs,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use crate::v1::{chat, error, tool};
+
+// -----------------------------------------------------------------------------
+// Definitions
+
+/// Counts the tokens of a text, for the dataset statistics.
+///
+/// It's implemented for any `Fn(&str) -> usize` closure (e.g. wrapping a real tokenizer),
+/// and by [EstimatedTokenCounter].
+pub trait DatasetTokenCounter {
+    fn count_tokens(&self, text: &str) -> usize;
+}
+impl<F: Fn(&str) -> usize> DatasetTokenCounter for F {
+    fn count_tokens(&self, text: &str) -> usize {
+        self(text)
+    }
+}
+
+/// A [DatasetTokenCounter] estimating 1 token per 4 characters.
+///
+/// This is only a rough approximation (fairly accurate for English prose, less so for code
+/// or other languages), good enough to size a dataset before uploading it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EstimatedTokenCounter;
+impl DatasetTokenCounter for EstimatedTokenCounter {
+    fn count_tokens(&self, text: &str) -> usize {
+        text.chars().count().div_ceil(4)
+    }
+}
+
+/// A fine-tuning training sample, i.e. a JSONL line of a training file.
+///
+/// See the [fine-tuning guide](https://docs.mistral.ai/capabilities/finetuning/) for more information.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DatasetSample {
+    pub messages: Vec<chat::ChatMessage>,
+    /// The tools the assistant messages can call (function calling samples only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<tool::Tool>>,
+}
+impl DatasetSample {
+    pub fn new(messages: Vec<chat::ChatMessage>) -> Self {
+        Self {
+            messages,
+            tools: None,
+        }
+    }
+
+    /// Checks that the sample follows the fine-tuning rules:
+    ///
+    /// - an optional `system` message first,
+    /// - then alternating `user` and `assistant` messages,
+    /// - each `assistant` message calling tools being followed by one `tool` message per call,
+    /// - no empty message and a last `assistant` message,
+    /// - tool calls with an ID, a declared function and JSON object arguments.
+    pub fn validate(&self) -> Result<(), error::DatasetSampleError> {
+        if self.messages.is_empty() {
+            return Err(error::DatasetSampleError::NoMessages);
+        }
+
+        let declared_function_names: Option<HashSet<&str>> = self.tools.as_ref().map(|tools| {
+            tools
+                .iter()
+                .map(|tool| tool.function.name.as_str())
+                .collect()
+        });
+        let mut pending_tool_call_ids: Vec<&str> = Vec::new();
+        let mut previous_role: Option<&chat::ChatMessageRole> = None;
+        for (index, message) in self.messages.iter().enumerate() {
+            let role = &message.role;
+            if let chat::ChatMessageRole::Unknown(role) = role {
+                return Err(error::DatasetSampleError::UnknownRole {
+                    index,
+                    role: role.clone(),
+                });
+            }
+
+            let expected_roles: &[&str] = match previous_role {
+                None => &["system", "user"],
+                Some(chat::ChatMessageRole::System) => &["user"],
+                Some(chat::ChatMessageRole::User) => &["assistant"],
+                Some(chat::ChatMessageRole::Assistant | chat::ChatMessageRole::Tool)
+                    if !pending_tool_call_ids.is_empty() =>
+                {
+                    &["tool"]
+                }
+                Some(chat::ChatMessageRole::Assistant) => &["user"],
+                _ => &["assistant"],
+            };
+            if !expected_roles.contains(&get_role_name(role)) {
+                return Err(error::DatasetSampleError::UnexpectedRole {
+                    index,
+                    role: get_role_name(role).to_string(),
+                    expected: expected_roles
+                        .iter()
+                        .map(|role| format!("`{}`", role))
+                        .collect::<Vec<_>>()
+                        .join(" or "),
+                });
+            }
+
+            let tool_calls = message.tool_calls.as_deref().unwrap_or_default();
+            if is_content_empty(&message.content)
+                && (*role != chat::ChatMessageRole::Assistant || tool_calls.is_empty())
+            {
+                return Err(error::DatasetSampleError::EmptyMessage {
+                    index,
+                    role: get_role_name(role).to_string(),
+                });
+            }
+
+            match role {
+                chat::ChatMessageRole::Assistant => {
+                    for tool_call in tool_calls {
+                        let message = get_tool_call_error(
+                            tool_call,
+                            &pending_tool_call_ids,
+                            declared_function_names.as_ref(),
+                        );
+                        if let Some(message) = message {
+                            return Err(error::DatasetSampleError::InvalidToolCall {
+                                index,
+                                message,
+                            });
+                        }
+                        if let Some(id) = tool_call.id.as_deref() {
+                            pending_tool_call_ids.push(id);
+                        }
+                    }
+                }
+                chat::ChatMessageRole::Tool => {
+                    let position = message.tool_call_id.as_deref().and_then(|tool_call_id| {
+                        pending_tool_call_ids
+                            .iter()
+                            .position(|id| *id == tool_call_id)
+                    });
+                    match position {
+                        Some(position) => {
+                            pending_tool_call_ids.remove(position);
+                        }
+                        None => {
+                            return Err(error::DatasetSampleError::UnmatchedToolResult {
+                                index,
+                                tool_call_id: message.tool_call_id.clone(),
+                            })
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            previous_role = Some(role);
+        }
+
+        if !pending_tool_call_ids.is_empty() {
+            return Err(error::DatasetSampleError::UnansweredToolCalls {
+                ids: pending_tool_call_ids
+                    .iter()
+                    .map(|id| format!("`{}`", id))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            });
+        }
+        match previous_role {
+            Some(chat::ChatMessageRole::Assistant) => Ok(()),
+            role => Err(error::DatasetSampleError::InvalidLastMessage {
+                role: role.map(get_role_name).unwrap_or_default().to_string(),
+            }),
+        }
+    }
+
+    /// Returns the number of tokens of the sample messages and of its assistant messages.
+    fn count_tokens(&self, token_counter: &impl DatasetTokenCounter) -> (usize, usize) {
+        self.messages
+            .iter()
+            .fold((0, 0), |(token_count, assistant_token_count), message| {
+                let mut text = message.content.text();
+                for tool_call in message.tool_calls.iter().flatten() {
+                    text.push_str(&tool_call.function.name);
+                    text.push_str(&tool_call.function.arguments);
+                }
+                let message_token_count = token_counter.count_tokens(&text);
+
+                if message.role == chat::ChatMessageRole::Assistant {
+                    (
+                        token_count + message_token_count,
+                        assistant_token_count + message_token_count,
+                    )
+                } else {
+                    (token_count + message_token_count, assistant_token_count)
+                }
+            })
+    }
+}
+
+/// A validated fine-tuning dataset, written as a JSONL training (or validation) file.
+///
+/// # Examples
+///
+/// ```
+/// use mistralai_client::v1::{chat::ChatMessage, dataset::Dataset};
+///
+/// let mut dataset = Dataset::new();
+/// dataset
+///     .push_conversation(vec![
+///         ChatMessage::new_user_message("What's the capital of France?"),
+///         ChatMessage::new_assistant_message("Paris.", None),
+///     ])
+///     .unwrap();
+///
+/// let (training_dataset, validation_dataset) = dataset.split(0.0, 42);
+/// assert_eq!(training_dataset.len(), 1);
+/// assert!(validation_dataset.is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Dataset {
+    samples: Vec<DatasetSample>,
+}
+impl Dataset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads and validates a JSONL dataset, failing on the first invalid line.
+    ///
+    /// Use [validate_jsonl] to get all the errors of a dataset instead.
+    pub fn from_jsonl(reader: impl BufRead) -> Result<Self, error::DatasetError> {
+        let mut dataset = Self::new();
+        for line_result in read_samples(reader) {
+            dataset.samples.push(line_result?);
+        }
+
+        Ok(dataset)
+    }
+
+    /// See [Dataset::from_jsonl] for more information.
+    pub fn from_jsonl_file(path: impl AsRef<Path>) -> Result<Self, error::DatasetError> {
+        let file = fs::File::open(path)
+            .map_err(|error| error::DatasetError::UnreadableFile(error.to_string()))?;
+
+        Self::from_jsonl(BufReader::new(file))
+    }
+
+    /// Adds a sample to the dataset, after validating it.
+    pub fn push(&mut self, sample: DatasetSample) -> Result<(), error::DatasetSampleError> {
+        sample.validate()?;
+        self.samples.push(sample);
+
+        Ok(())
+    }
+
+    /// Adds a conversation to the dataset, after validating it.
+    ///
+    /// See [DatasetSample::validate] for the rules a conversation must follow.
+    pub fn push_conversation(
+        &mut self,
+        messages: Vec<chat::ChatMessage>,
+    ) -> Result<(), error::DatasetSampleError> {
+        self.push(DatasetSample::new(messages))
+    }
+
+    pub fn samples(&self) -> &[DatasetSample] {
+        &self.samples
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    pub fn statistics(&self, token_counter: &impl DatasetTokenCounter) -> DatasetStatistics {
+        DatasetStatistics::from_samples(&self.samples, token_counter)
+    }
+
+    /// Shuffles the samples and splits them into a training and a validation dataset.
+    ///
+    /// # Arguments
+    ///
+    /// * `validation_ratio` - The share of samples moved to the validation dataset, between `0.0` and `1.0`.
+    /// * `seed` - The shuffling seed, the same seed always producing the same split.
+    pub fn split(self, validation_ratio: f64, seed: u64) -> (Dataset, Dataset) {
+        let mut samples = self.samples;
+        let mut state = seed;
+        for index in (1..samples.len()).rev() {
+            let other_index = (get_next_random_number(&mut state) % (index as u64 + 1)) as usize;
+            samples.swap(index, other_index);
+        }
+
+        let validation_sample_count =
+            ((samples.len() as f64) * validation_ratio.clamp(0.0, 1.0)).round() as usize;
+        let training_samples = samples.split_off(validation_sample_count);
+
+        (
+            Dataset {
+                samples: training_samples,
+            },
+            Dataset { samples },
+        )
+    }
+
+    /// Writes the dataset as JSONL, one sample per line.
+    pub fn write_jsonl(&self, mut writer: impl Write) -> Result<(), error::DatasetError> {
+        for sample in &self.samples {
+            let line = serde_json::to_string(sample)
+                .map_err(|error| error::DatasetError::UnwritableFile(error.to_string()))?;
+            writeln!(writer, "{}", line)
+                .map_err(|error| error::DatasetError::UnwritableFile(error.to_string()))?;
+        }
+
+        writer
+            .flush()
+            .map_err(|error| error::DatasetError::UnwritableFile(error.to_string()))
+    }
+
+    /// See [Dataset::write_jsonl] for more information.
+    pub fn write_jsonl_file(&self, path: impl AsRef<Path>) -> Result<(), error::DatasetError> {
+        let file = fs::File::create(path)
+            .map_err(|error| error::DatasetError::UnwritableFile(error.to_string()))?;
+
+        self.write_jsonl(BufWriter::new(file))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Report
+
+/// The token statistics of a dataset, as counted by a [DatasetTokenCounter].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DatasetStatistics {
+    pub sample_count: usize,
+    pub message_count: usize,
+    pub token_count: usize,
+    /// The number of tokens of the assistant messages, i.e. the ones the model is trained on.
+    pub assistant_token_count: usize,
+    pub min_sample_token_count: usize,
+    pub max_sample_token_count: usize,
+    pub mean_sample_token_count: f64,
+}
+impl DatasetStatistics {
+    pub fn from_samples(
+        samples: &[DatasetSample],
+        token_counter: &impl DatasetTokenCounter,
+    ) -> Self {
+        let mut statistics = Self::default();
+        for sample in samples {
+            statistics.add_sample(sample, token_counter);
+        }
+
+        statistics
+    }
+
+    fn add_sample(&mut self, sample: &DatasetSample, token_counter: &impl DatasetTokenCounter) {
+        let (token_count, assistant_token_count) = sample.count_tokens(token_counter);
+
+        self.min_sample_token_count = if self.sample_count == 0 {
+            token_count
+        } else {
+            self.min_sample_token_count.min(token_count)
+        };
+        self.max_sample_token_count = self.max_sample_token_count.max(token_count);
+        self.sample_count += 1;
+        self.message_count += sample.messages.len();
+        self.token_count += token_count;
+        self.assistant_token_count += assistant_token_count;
+        self.mean_sample_token_count = self.token_count as f64 / self.sample_count as f64;
+    }
+}
+
+#[derive(Debug)]
+pub struct DatasetValidationReport {
+    /// The statistics of the valid samples.
+    pub statistics: DatasetStatistics,
+    /// The errors of the invalid lines, in order.
+    pub errors: Vec<error::DatasetError>,
+}
+impl DatasetValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Validates a JSONL dataset line by line, collecting all the errors with their line number.
+///
+/// Only an unreadable input fails the validation itself.
+///
+/// # Examples
+///
+/// ```
+/// use mistralai_client::v1::dataset::{validate_jsonl, EstimatedTokenCounter};
+///
+/// let jsonl = r#"{"messages": [{"role": "user", "content": "Hi!"}, {"role": "assistant", "content": "Hello!"}]}
+/// {"messages": [{"role": "user", "content": "Hi!"}]}"#;
+///
+/// let report = validate_jsonl(jsonl.as_bytes(), &EstimatedTokenCounter).unwrap();
+/// assert_eq!(report.statistics.sample_count, 1);
+/// assert_eq!(
+///     report.errors[0].to_string(),
+///     "Line 2: The last message must be an `assistant` message, not a `user` one."
+/// );
+/// ```
+pub fn validate_jsonl(
+    reader: impl BufRead,
+    token_counter: &impl DatasetTokenCounter,
+) -> Result<DatasetValidationReport, error::DatasetError> {
+    let mut report = DatasetValidationReport {
+        statistics: DatasetStatistics::default(),
+        errors: Vec::new(),
+    };
+    for line_result in read_samples(reader) {
+        match line_result {
+            Ok(sample) => report.statistics.add_sample(&sample, token_counter),
+            Err(error @ error::DatasetError::UnreadableFile(_)) => return Err(error),
+            Err(error) => report.errors.push(error),
+        }
+    }
+
+    Ok(report)
+}
+
+/// See [validate_jsonl] for more information.
+pub fn validate_jsonl_file(
+    path: impl AsRef<Path>,
+    token_counter: &impl DatasetTokenCounter,
+) -> Result<DatasetValidationReport, error::DatasetError> {
+    let file = fs::File::open(path)
+        .map_err(|error| error::DatasetError::UnreadableFile(error.to_string()))?;
+
+    validate_jsonl(BufReader::new(file), token_counter)
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+/// Returns the validated samples of the non-blank lines.
+fn read_samples(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<DatasetSample, error::DatasetError>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line_result)| {
+            let line = index + 1;
+            let content = match line_result {
+                Ok(content) if content.trim().is_empty() => return None,
+                Ok(content) => content,
+                Err(error) => {
+                    return Some(Err(error::DatasetError::UnreadableFile(error.to_string())))
+                }
+            };
+
+            let result = serde_json::from_str::<DatasetSample>(&content)
+                .map_err(|error| error::DatasetError::InvalidJson {
+                    line,
+                    message: error.to_string(),
+                })
+                .and_then(|sample| match sample.validate() {
+                    Ok(()) => Ok(sample),
+                    Err(error) => Err(error::DatasetError::InvalidSample { line, error }),
+                });
+
+            Some(result)
+        })
+}
+
+fn get_role_name(role: &chat::ChatMessageRole) -> &str {
+    match role {
+        chat::ChatMessageRole::System => "system",
+        chat::ChatMessageRole::Assistant => "assistant",
+        chat::ChatMessageRole::User => "user",
+        chat::ChatMessageRole::Tool => "tool",
+        chat::ChatMessageRole::Unknown(role) => role,
+    }
+}
+
+fn is_content_empty(content: &chat::ChatMessageContent) -> bool {
+    match content {
+        chat::ChatMessageContent::Text(text) => text.trim().is_empty(),
+        chat::ChatMessageContent::Chunks(chunks) => chunks.iter().all(|chunk| match chunk {
+            chat::ChatMessageContentChunk::Text { text } => text.trim().is_empty(),
+            _ => false,
+        }),
+    }
+}
+
+fn get_tool_call_error(
+    tool_call: &tool::ToolCall,
+    pending_tool_call_ids: &[&str],
+    declared_function_names: Option<&HashSet<&str>>,
+) -> Option<String> {
+    let function_name = tool_call.function.name.as_str();
+    match tool_call.id.as_deref() {
+        None | Some("") => return Some(format!("the `{}` call has no ID", function_name)),
+        Some(id) if pending_tool_call_ids.contains(&id) => {
+            return Some(format!("the `{}` ID is used twice", id))
+        }
+        _ => {}
+    }
+
+    if function_name.is_empty() {
+        return Some("the function name is empty".to_string());
+    }
+    if declared_function_names.is_some_and(|names| !names.contains(function_name)) {
+        return Some(format!(
+            "the `{}` function isn't declared in the sample tools",
+            function_name
+        ));
+    }
+    match serde_json::from_str::<serde_json::Value>(&tool_call.function.arguments) {
+        Ok(serde_json::Value::Object(_)) => None,
+        Ok(_) => Some(format!(
+            "the `{}` call arguments aren't a JSON object",
+            function_name
+        )),
+        Err(error) => Some(format!(
+            "the `{}` call arguments aren't valid JSON: {}",
+            function_name, error
+        )),
+    }
+}
+
+/// Returns the next pseudo-random number of a SplitMix64 sequence.
+fn get_next_random_number(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut number = *state;
+    number = (number ^ (number >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    number = (number ^ (number >> 27)).wrapping_mul(0x94D049BB133111EB);
+
+    number ^ (number >> 31)
+}
@@ -1,21 +1,15 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::v1::{common, constants};
 
 // -----------------------------------------------------------------------------
 // Request
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct EmbeddingRequestOptions {
     pub encoding_format: Option<EmbeddingRequestEncodingFormat>,
 }
-impl Default for EmbeddingRequestOptions {
-    fn default() -> Self {
-        Self {
-            encoding_format: None,
-        }
-    }
-}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EmbeddingRequest {
@@ -56,6 +50,9 @@ pub struct EmbeddingResponse {
     pub model: constants::EmbedModel,
     pub data: Vec<EmbeddingResponseDataItem>,
     pub usage: common::ResponseUsage,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -63,4 +60,7 @@ pub struct EmbeddingResponseDataItem {
     pub index: u32,
     pub embedding: Vec<f32>,
     pub object: String,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
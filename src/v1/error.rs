@@ -1,9 +1,13 @@
 use std::error::Error;
 use std::fmt;
 
+use crate::v1::{chat, chat_validation, common, model_registry, moderation};
+
 #[derive(Debug)]
 pub struct ApiError {
     pub message: String,
+    /// What caused the error, e.g. an error HTTP status code.
+    pub kind: ApiErrorKind,
 }
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -11,6 +15,57 @@ impl fmt::Display for ApiError {
     }
 }
 impl Error for ApiError {}
+impl ApiError {
+    /// Returns the HTTP status code of the API error response, if the API answered.
+    pub fn status_code(&self) -> Option<u16> {
+        match self.kind {
+            ApiErrorKind::Status(status_code) => Some(status_code),
+            _ => None,
+        }
+    }
+
+    /// Returns whether sending the same request again may succeed, i.e. after a transport error
+    /// (e.g. a timeout), a rate limit (`429`) or a server error (`5xx`).
+    pub fn is_retryable(&self) -> bool {
+        match self.kind {
+            ApiErrorKind::Status(status_code) => {
+                status_code == 408 || status_code == 429 || status_code >= 500
+            }
+            ApiErrorKind::Transport => true,
+            _ => false,
+        }
+    }
+
+    pub(crate) fn from_reqwest_error(error: reqwest::Error) -> Self {
+        let kind = if error.is_decode() {
+            ApiErrorKind::Serialization
+        } else if let Some(status) = error.status() {
+            ApiErrorKind::Status(status.as_u16())
+        } else if error.is_builder() {
+            ApiErrorKind::Other
+        } else {
+            ApiErrorKind::Transport
+        };
+
+        Self {
+            message: error.to_string(),
+            kind,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ApiErrorKind {
+    /// The API answered with this error HTTP status code.
+    Status(u16),
+    /// The request couldn't be sent or its response couldn't be received, e.g. after a timeout.
+    Transport,
+    /// The request couldn't be serialized or the response couldn't be deserialized.
+    Serialization,
+    /// Any other error, e.g. an invalid argument or a file error.
+    Other,
+}
 
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum ClientError {
@@ -21,3 +76,145 @@ pub enum ClientError {
     #[error("Failed to read the response text.")]
     UnreadableResponseText,
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChatParseError {
+    #[error(transparent)]
+    Api(#[from] ApiError),
+    #[error("The response has no choice to parse.")]
+    MissingChoice,
+    #[error(
+        "Failed to parse the assistant message content: {message}. Raw content: {raw_content}"
+    )]
+    InvalidContent {
+        message: String,
+        raw_content: String,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ChatValidationError {
+    #[error("{error}")]
+    Api {
+        error: ApiError,
+        /// The attempts made before the failed request, each one being invalid.
+        attempts: Vec<chat_validation::ChatValidationAttempt>,
+        /// The usage of these attempts combined (boxed to keep the error small).
+        usage: Box<common::ResponseUsage>,
+    },
+    #[error("The response has no choice to validate.")]
+    MissingChoice {
+        /// All the attempts, the last one being the one without choice.
+        attempts: Vec<chat_validation::ChatValidationAttempt>,
+        /// The usage of all the attempts combined (boxed to keep the error small).
+        usage: Box<common::ResponseUsage>,
+    },
+    #[error("The assistant message content is still invalid after {} attempts: {message}. Raw content: {raw_content}", .attempts.len())]
+    InvalidContent {
+        /// All the attempts, each one being invalid.
+        attempts: Vec<chat_validation::ChatValidationAttempt>,
+        /// The usage of all the attempts combined (boxed to keep the error small).
+        usage: Box<common::ResponseUsage>,
+        message: String,
+        raw_content: String,
+    },
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ModelCapabilityError {
+    #[error("The `{model}` model doesn't support {feature}.")]
+    UnsupportedFeature {
+        model: String,
+        feature: model_registry::ModelFeature,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ModerationError {
+    #[error(transparent)]
+    Api(#[from] ApiError),
+    #[error("The user input is flagged by the moderation: {}.", moderation::join_categories(.categories))]
+    InputBlocked {
+        categories: Vec<moderation::ModerationCategory>,
+    },
+    #[error("The assistant output is flagged by the moderation: {}.", moderation::join_categories(.categories))]
+    OutputBlocked {
+        categories: Vec<moderation::ModerationCategory>,
+        /// The blocked chat response.
+        response: Box<chat::ChatResponse>,
+    },
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum OpenApiError {
+    #[error("Failed to read the OpenAPI document: {0}")]
+    UnreadableDocument(String),
+    #[error("Invalid OpenAPI document: {0}")]
+    InvalidDocument(String),
+    #[error(
+        "You must either declare an absolute URL in the OpenAPI document `servers` or specify it in `OpenApiToolsParams.base_url`."
+    )]
+    MissingBaseUrl,
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DatasetError {
+    #[error("Failed to read the dataset: {0}")]
+    UnreadableFile(String),
+    #[error("Failed to write the dataset: {0}")]
+    UnwritableFile(String),
+    #[error("Line {line}: Invalid JSON sample: {message}")]
+    InvalidJson { line: usize, message: String },
+    #[error("Line {line}: {error}")]
+    InvalidSample {
+        line: usize,
+        error: DatasetSampleError,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DatasetSampleError {
+    #[error("The sample has no messages.")]
+    NoMessages,
+    #[error("Unknown `{role}` role at `messages[{index}]`.")]
+    UnknownRole { index: usize, role: String },
+    #[error("Unexpected `{role}` message at `messages[{index}]`, expected a {expected} message.")]
+    UnexpectedRole {
+        index: usize,
+        role: String,
+        expected: String,
+    },
+    #[error("Empty `{role}` message at `messages[{index}]`.")]
+    EmptyMessage { index: usize, role: String },
+    #[error("Invalid tool call at `messages[{index}]`: {message}.")]
+    InvalidToolCall { index: usize, message: String },
+    #[error("The tool result at `messages[{index}]` doesn't answer any pending tool call (`tool_call_id`: {tool_call_id:?}).")]
+    UnmatchedToolResult {
+        index: usize,
+        tool_call_id: Option<String>,
+    },
+    #[error("The tool calls {ids} have no tool result.")]
+    UnansweredToolCalls { ids: String },
+    #[error("The last message must be an `assistant` message, not a `{role}` one.")]
+    InvalidLastMessage { role: String },
+}
+
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum ImageError {
+    #[error("Failed to read the image file: {0}")]
+    UnreadableFile(String),
+    #[error("Unsupported image format: {0}. Supported formats are PNG, JPEG, WEBP and GIF.")]
+    UnsupportedFormat(String),
+    #[error("The image is too large ({size} bytes). The maximum size is {max_size} bytes.")]
+    TooLarge { size: usize, max_size: usize },
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum OcrImageError {
+    #[error("The `{0}` image has no base64 data. Set `include_image_base64` to `true` to get it.")]
+    MissingData(String),
+    #[error("Failed to decode the `{id}` image: {message}")]
+    InvalidData { id: String, message: String },
+    #[error("Failed to write the `{id}` image file: {message}")]
+    UnwritableFile { id: String, message: String },
+}
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters for the files list request.
+#[derive(Clone, Debug, Default)]
+pub struct FileListParams {
+    /// The page number, starting at `0`.
+    ///
+    /// Defaults to `None`.
+    pub page: Option<u32>,
+    /// The number of files per page.
+    ///
+    /// Defaults to `None`.
+    pub page_size: Option<u32>,
+    /// Only list the files uploaded for this purpose.
+    ///
+    /// Defaults to `None`.
+    pub purpose: Option<FilePurpose>,
+    /// Only list the files containing these sample types.
+    ///
+    /// Defaults to `None`.
+    pub sample_type: Option<Vec<FileSampleType>>,
+    /// Only list the files whose name contains this text.
+    ///
+    /// Defaults to `None`.
+    pub search: Option<String>,
+    /// Only list the files coming from these sources.
+    ///
+    /// Defaults to `None`.
+    pub source: Option<Vec<FileSource>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileListRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<FilePurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_type: Option<Vec<FileSampleType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Vec<FileSource>>,
+}
+impl FileListRequest {
+    pub fn new(options: Option<FileListParams>) -> Self {
+        let FileListParams {
+            page,
+            page_size,
+            purpose,
+            sample_type,
+            search,
+            source,
+        } = options.unwrap_or_default();
+
+        Self {
+            page,
+            page_size,
+            purpose,
+            sample_type,
+            search,
+            source,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileSignedUrlRequest {
+    /// The number of hours the URL is valid for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<u32>,
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileListResponse {
+    pub object: String,
+    pub data: Vec<FileResponse>,
+    /// The total number of files matching the filters (across all the pages).
+    pub total: u32,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// The metadata of an uploaded file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileResponse {
+    pub id: String,
+    pub object: String,
+    /// The file size, in bytes.
+    pub bytes: u64,
+    /// Unix timestamp (in seconds).
+    pub created_at: u64,
+    pub filename: String,
+    pub purpose: FilePurpose,
+    pub sample_type: Option<FileSampleType>,
+    /// The number of lines of JSONL files.
+    pub num_lines: Option<u32>,
+    pub source: Option<FileSource>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileDeleteResponse {
+    pub id: String,
+    pub object: String,
+    pub deleted: bool,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileSignedUrlResponse {
+    /// The temporary URL to download the file content from, without authentication.
+    pub url: String,
+}
+
+// -----------------------------------------------------------------------------
+// Enums
+
+/// What an uploaded file is used for.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FilePurpose {
+    #[serde(rename = "fine-tune")]
+    FineTune,
+    #[serde(rename = "batch")]
+    Batch,
+    #[serde(rename = "ocr")]
+    Ocr,
+    /// A purpose unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl FilePurpose {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::FineTune => "fine-tune",
+            Self::Batch => "batch",
+            Self::Ocr => "ocr",
+            Self::Unknown(purpose) => purpose,
+        }
+    }
+}
+impl fmt::Display for FilePurpose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FileSampleType {
+    #[serde(rename = "pretrain")]
+    Pretrain,
+    #[serde(rename = "instruct")]
+    Instruct,
+    #[serde(rename = "batch_request")]
+    BatchRequest,
+    #[serde(rename = "batch_result")]
+    BatchResult,
+    #[serde(rename = "batch_error")]
+    BatchError,
+    /// A sample type unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FileSource {
+    #[serde(rename = "upload")]
+    Upload,
+    #[serde(rename = "repository")]
+    Repository,
+    #[serde(rename = "mistral")]
+    Mistral,
+    /// A source unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::v1::{chat, chat_stream, common, constants};
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters for the fill-in-the-middle (FIM) request.
+///
+/// See the [API documentation](https://docs.mistral.ai/api/#tag/fim) for more information.
+#[derive(Clone, Debug, Default)]
+pub struct FimParams {
+    /// The maximum number of tokens to generate in the completion.
+    ///
+    /// Defaults to `None`.
+    pub max_tokens: Option<u32>,
+    /// The minimum number of tokens to generate in the completion.
+    ///
+    /// Defaults to `None`.
+    pub min_tokens: Option<u32>,
+    /// The seed to use for random sampling. If set, different calls will generate deterministic results.
+    ///
+    /// Defaults to `None`.
+    pub random_seed: Option<u32>,
+    /// Stop generating tokens when one of these sequences is generated.
+    ///
+    /// Defaults to `None`.
+    pub stop: Option<Vec<String>>,
+    /// The code following the completion. The model generates the code between `prompt` and `suffix`.
+    ///
+    /// Defaults to `None`.
+    pub suffix: Option<String>,
+    /// What sampling temperature to use, between `Some(0.0)` and `Some(1.0)`.
+    ///
+    /// Defaults to `None` (the model default).
+    pub temperature: Option<f32>,
+    /// Nucleus sampling, where the model considers the results of the tokens with `top_p` probability mass.
+    ///
+    /// Defaults to `None` (the model default).
+    pub top_p: Option<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FimRequest {
+    pub model: constants::Model,
+    /// The code preceding the completion.
+    pub prompt: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub random_seed: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    pub stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+}
+impl FimRequest {
+    pub fn new(
+        model: constants::Model,
+        prompt: String,
+        stream: bool,
+        options: Option<FimParams>,
+    ) -> Self {
+        let FimParams {
+            max_tokens,
+            min_tokens,
+            random_seed,
+            stop,
+            suffix,
+            temperature,
+            top_p,
+        } = options.unwrap_or_default();
+
+        Self {
+            model,
+            prompt,
+
+            max_tokens,
+            min_tokens,
+            random_seed,
+            stop,
+            stream,
+            suffix,
+            temperature,
+            top_p,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FimResponse {
+    pub id: String,
+    pub object: String,
+    /// Unix timestamp (in seconds).
+    pub created: u32,
+    pub model: constants::Model,
+    /// The completions, as assistant messages whose content is the generated middle code.
+    pub choices: Vec<chat::ChatResponseChoice>,
+    pub usage: common::ResponseUsage,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// FIM streamed chunks share the chat completion chunks shape.
+pub type FimStreamChunk = chat_stream::ChatStreamChunk;
@@ -0,0 +1,446 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+use crate::v1::{constants, utils};
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters for the fine-tuning job creation request.
+///
+/// See the [API documentation](https://docs.mistral.ai/api/#tag/fine-tuning) for more information.
+#[derive(Clone, Debug, Default)]
+pub struct FineTuningJobParams {
+    /// Whether to start the job as soon as it's validated.
+    /// Otherwise, it must be started via [Client::start_fine_tuning_job](crate::v1::client::Client::start_fine_tuning_job).
+    ///
+    /// Defaults to `None`.
+    pub auto_start: Option<bool>,
+    /// The targets the classifier model learns to predict (`Classifier` jobs only).
+    ///
+    /// Defaults to `None`.
+    pub classifier_targets: Option<Vec<FineTuningClassifierTarget>>,
+    /// The training hyperparameters.
+    ///
+    /// Defaults to `None`.
+    pub hyperparameters: Option<FineTuningHyperparameters>,
+    /// The integrations reporting the training metrics (e.g. Weights & Biases).
+    ///
+    /// Defaults to `None`.
+    pub integrations: Option<Vec<FineTuningIntegration>>,
+    /// Whether to fine-tune a chat completion model or a classifier model.
+    ///
+    /// Defaults to `None` (a `Completion` job).
+    pub job_type: Option<FineTuningJobType>,
+    /// The suffix added to the fine-tuned model name.
+    ///
+    /// Defaults to `None`.
+    pub suffix: Option<String>,
+    /// The IDs of the uploaded files used to evaluate the model during the training.
+    ///
+    /// Defaults to `None`.
+    pub validation_files: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FineTuningJobRequest {
+    pub model: constants::Model,
+    pub training_files: Vec<FineTuningTrainingFile>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_start: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub classifier_targets: Option<Vec<FineTuningClassifierTarget>>,
+    pub hyperparameters: FineTuningHyperparameters,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<Vec<FineTuningIntegration>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_type: Option<FineTuningJobType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_files: Option<Vec<String>>,
+}
+impl FineTuningJobRequest {
+    pub fn new(
+        model: constants::Model,
+        training_files: Vec<FineTuningTrainingFile>,
+        options: Option<FineTuningJobParams>,
+    ) -> Self {
+        let FineTuningJobParams {
+            auto_start,
+            classifier_targets,
+            hyperparameters,
+            integrations,
+            job_type,
+            suffix,
+            validation_files,
+        } = options.unwrap_or_default();
+
+        Self {
+            model,
+            training_files,
+
+            auto_start,
+            classifier_targets,
+            hyperparameters: hyperparameters.unwrap_or_default(),
+            integrations,
+            job_type,
+            suffix,
+            validation_files,
+        }
+    }
+}
+
+/// An uploaded training file, with its weight among all the training files.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FineTuningTrainingFile {
+    pub file_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+}
+impl FineTuningTrainingFile {
+    pub fn new(file_id: &str) -> Self {
+        Self {
+            file_id: file_id.to_string(),
+            weight: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FineTuningJobType {
+    /// Fine-tunes a chat completion model.
+    #[serde(rename = "completion")]
+    Completion,
+    /// Fine-tunes a classifier model, used via the classification endpoints.
+    #[serde(rename = "classifier")]
+    Classifier,
+    /// A job type unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A target of a classifier model, e.g. a ticket `priority` with `low`, `medium` and `high` labels.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FineTuningClassifierTarget {
+    /// The target name, matching the key of the training samples `labels`.
+    pub name: String,
+    pub labels: Vec<String>,
+    /// The weight of the target in the training loss.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loss_function: Option<FineTuningClassifierLossFunction>,
+}
+impl FineTuningClassifierTarget {
+    pub fn new(name: &str, labels: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            labels,
+            weight: None,
+            loss_function: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FineTuningClassifierLossFunction {
+    /// Each input has exactly one label of the target.
+    #[serde(rename = "single_class")]
+    SingleClass,
+    /// Each input has any number of labels of the target.
+    #[serde(rename = "multi_class")]
+    MultiClass,
+    /// A loss function unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The training hyperparameters. The API picks a default value for each `None` one.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct FineTuningHyperparameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub training_steps: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub learning_rate: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_decay: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warmup_fraction: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epochs: Option<f64>,
+    /// The ratio of fill-in-the-middle samples (Codestral models only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fim_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_len: Option<u32>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct FineTuningIntegration {
+    pub r#type: FineTuningIntegrationType,
+    pub project: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The integration API key (never returned by the API).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_name: Option<String>,
+}
+impl FineTuningIntegration {
+    /// Constructs a Weights & Biases integration reporting to the `project`.
+    pub fn new_wandb(project: &str, api_key: &str) -> Self {
+        Self {
+            r#type: FineTuningIntegrationType::Wandb,
+            project: project.to_string(),
+            name: None,
+            api_key: Some(api_key.to_string()),
+            run_name: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FineTuningIntegrationType {
+    #[serde(rename = "wandb")]
+    Wandb,
+    /// An integration type unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The parameters for the fine-tuning jobs list request.
+#[derive(Clone, Debug, Default)]
+pub struct FineTuningJobListParams {
+    /// Only list the jobs created after this date (ISO 8601).
+    ///
+    /// Defaults to `None`.
+    pub created_after: Option<String>,
+    /// Only list the jobs created by the API key owner.
+    ///
+    /// Defaults to `None`.
+    pub created_by_me: Option<bool>,
+    /// Only list the jobs fine-tuning this model.
+    ///
+    /// Defaults to `None`.
+    pub model: Option<String>,
+    /// The page number, starting at `0`.
+    ///
+    /// Defaults to `None`.
+    pub page: Option<u32>,
+    /// The number of jobs per page.
+    ///
+    /// Defaults to `None`.
+    pub page_size: Option<u32>,
+    /// Only list the jobs with this status.
+    ///
+    /// Defaults to `None`.
+    pub status: Option<FineTuningJobStatus>,
+    /// Only list the jobs with this fine-tuned model suffix.
+    ///
+    /// Defaults to `None`.
+    pub suffix: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FineTuningJobListRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by_me: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FineTuningJobStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+}
+impl FineTuningJobListRequest {
+    pub fn new(options: Option<FineTuningJobListParams>) -> Self {
+        let FineTuningJobListParams {
+            created_after,
+            created_by_me,
+            model,
+            page,
+            page_size,
+            status,
+            suffix,
+        } = options.unwrap_or_default();
+
+        Self {
+            created_after,
+            created_by_me,
+            model,
+            page,
+            page_size,
+            status,
+            suffix,
+        }
+    }
+}
+
+/// The parameters of [Client::watch_fine_tuning_job](crate::v1::client::Client::watch_fine_tuning_job).
+#[derive(Clone, Debug)]
+pub struct FineTuningJobWatchParams {
+    /// The delay between two polls after a status change.
+    ///
+    /// Defaults to 5 seconds.
+    pub initial_interval: Duration,
+    /// The maximum delay between two polls.
+    ///
+    /// Defaults to 1 minute.
+    pub max_interval: Duration,
+    /// The factor the delay is multiplied by after each poll without status change (or failed).
+    /// Values below `1.0` (or NaN) are treated as `1.0`.
+    ///
+    /// Defaults to `2.0`.
+    pub multiplier: f64,
+}
+impl FineTuningJobWatchParams {
+    /// Returns the delay following the `interval` one.
+    pub(crate) fn get_next_interval(&self, interval: Duration) -> Duration {
+        utils::get_next_backoff_interval(interval, self.multiplier, self.max_interval)
+    }
+}
+impl Default for FineTuningJobWatchParams {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(5),
+            max_interval: Duration::from_secs(60),
+            multiplier: 2.0,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FineTuningJobListResponse {
+    pub object: String,
+    pub data: Vec<FineTuningJobResponse>,
+    /// The total number of jobs matching the filters (across all the pages).
+    pub total: u32,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FineTuningJobResponse {
+    pub id: String,
+    pub object: String,
+    pub model: constants::Model,
+    pub status: FineTuningJobStatus,
+    /// The job type (`None` for jobs created before classifier jobs existed).
+    pub job_type: Option<FineTuningJobType>,
+    pub auto_start: bool,
+    pub hyperparameters: FineTuningHyperparameters,
+    pub training_files: Vec<String>,
+    #[serde(default)]
+    pub validation_files: Vec<String>,
+    /// Unix timestamp (in seconds).
+    pub created_at: u64,
+    /// Unix timestamp (in seconds).
+    pub modified_at: u64,
+    /// The ID of the fine-tuned model, once the job succeeded.
+    pub fine_tuned_model: Option<String>,
+    pub suffix: Option<String>,
+    #[serde(default)]
+    pub integrations: Vec<FineTuningIntegration>,
+    /// The classifier targets (`Classifier` jobs only).
+    #[serde(default)]
+    pub classifier_targets: Vec<FineTuningClassifierTarget>,
+    pub trained_tokens: Option<u64>,
+    /// The job events, from the most recent one (job details only).
+    #[serde(default)]
+    pub events: Vec<FineTuningJobEvent>,
+    /// The saved model checkpoints, from the most recent one (job details only).
+    #[serde(default)]
+    pub checkpoints: Vec<FineTuningJobCheckpoint>,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl FineTuningJobResponse {
+    /// Returns whether the job status won't change anymore without any action,
+    /// i.e. it's over or validated and waiting to be started.
+    pub fn is_settled(&self) -> bool {
+        self.status.is_terminal()
+            || (self.status == FineTuningJobStatus::Validated && !self.auto_start)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FineTuningJobEvent {
+    /// The event name, e.g. `status-updated`.
+    pub name: String,
+    pub data: Option<serde_json::Value>,
+    /// Unix timestamp (in seconds).
+    pub created_at: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FineTuningJobCheckpoint {
+    pub metrics: FineTuningJobCheckpointMetrics,
+    pub step_number: u32,
+    /// Unix timestamp (in seconds).
+    pub created_at: u64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct FineTuningJobCheckpointMetrics {
+    pub train_loss: Option<f64>,
+    pub valid_loss: Option<f64>,
+    pub valid_mean_token_accuracy: Option<f64>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum FineTuningJobStatus {
+    #[serde(rename = "QUEUED")]
+    Queued,
+    #[serde(rename = "STARTED")]
+    Started,
+    #[serde(rename = "VALIDATING")]
+    Validating,
+    /// The job is validated, waiting to be started when created without `auto_start`.
+    #[serde(rename = "VALIDATED")]
+    Validated,
+    #[serde(rename = "RUNNING")]
+    Running,
+    #[serde(rename = "FAILED_VALIDATION")]
+    FailedValidation,
+    #[serde(rename = "FAILED")]
+    Failed,
+    #[serde(rename = "SUCCESS")]
+    Success,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    #[serde(rename = "CANCELLATION_REQUESTED")]
+    CancellationRequested,
+    /// A status unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl FineTuningJobStatus {
+    /// Returns whether the job is over and its status won't change anymore.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::FailedValidation | Self::Failed | Self::Success | Self::Cancelled
+        )
+    }
+}
@@ -1,10 +1,24 @@
+pub mod batch;
+pub mod batch_runner;
 pub mod chat;
+pub mod chat_classification;
 pub mod chat_stream;
+pub mod chat_validation;
+pub mod classification;
 pub mod client;
 pub mod common;
 pub mod constants;
+pub mod dataset;
 pub mod embedding;
 pub mod error;
+pub mod file;
+pub mod fim;
+pub mod fine_tuning;
+pub mod model;
 pub mod model_list;
+pub mod model_registry;
+pub mod moderation;
+pub mod ocr;
+pub mod openapi;
 pub mod tool;
 pub mod utils;
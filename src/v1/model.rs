@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters for the fine-tuned model update request.
+#[derive(Clone, Debug, Default)]
+pub struct ModelUpdateParams {
+    /// The new model display name.
+    ///
+    /// Defaults to `None`.
+    pub name: Option<String>,
+    /// The new model description.
+    ///
+    /// Defaults to `None`.
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModelUpdateRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+impl ModelUpdateRequest {
+    pub fn new(options: ModelUpdateParams) -> Self {
+        let ModelUpdateParams { name, description } = options;
+
+        Self { name, description }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModelDeleteResponse {
+    pub id: String,
+    pub object: String,
+    pub deleted: bool,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModelArchiveResponse {
+    pub id: String,
+    pub object: String,
+    pub archived: bool,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // -----------------------------------------------------------------------------
 // Response
@@ -7,6 +8,9 @@ use serde::{Deserialize, Serialize};
 pub struct ModelListResponse {
     pub object: String,
     pub data: Vec<ModelListData>,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -16,11 +20,63 @@ pub struct ModelListData {
     /// Unix timestamp (in seconds).
     pub created: u32,
     pub owned_by: String,
+    #[serde(default)]
     pub permission: Vec<ModelListDataPermission>,
-    // TODO Check this prop (seen in API responses but undocumented).
-    // pub root: ???,
+    /// Whether the model is a base or a fine-tuned model.
+    pub r#type: Option<ModelType>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// The features supported by the model.
+    #[serde(default)]
+    pub capabilities: ModelCapabilities,
+    /// The maximum number of tokens of the model context window.
+    pub max_context_length: Option<u32>,
+    /// The other IDs of the model (e.g. `mistral-large-latest` for `mistral-large-2411`).
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// The deprecation date of the model, if deprecated.
+    pub deprecation: Option<String>,
+    /// The model replacing this one once deprecated.
+    pub deprecation_replacement_model: Option<String>,
+    pub default_model_temperature: Option<f32>,
+    /// The ID of the fine-tuning job which created the model (fine-tuned models only).
+    pub job: Option<String>,
+    /// The base model of the fine-tuned model (fine-tuned models only).
+    pub root: Option<String>,
+    /// Whether the fine-tuned model is archived (fine-tuned models only).
+    pub archived: Option<bool>,
     // TODO Check this prop (seen in API responses but undocumented).
     // pub parent: ???,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum ModelType {
+    #[serde(rename = "base")]
+    Base,
+    #[serde(rename = "fine-tuned")]
+    FineTuned,
+    /// A model type unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The features supported by a model.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ModelCapabilities {
+    pub completion_chat: bool,
+    pub completion_fim: bool,
+    pub function_calling: bool,
+    pub fine_tuning: bool,
+    pub vision: bool,
+    pub classification: bool,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -39,4 +95,7 @@ pub struct ModelListDataPermission {
     pub is_blocking: bool,
     // TODO Check this prop (seen in API responses but undocumented).
     // pub group: ???,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
 }
@@ -0,0 +1,463 @@
+use log::{debug, warn};
+use std::{
+    collections::HashSet,
+    fmt,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::v1::{chat, error, fim, model_list};
+
+// -----------------------------------------------------------------------------
+// Registry
+
+/// The capabilities and limits of a model.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModelInfo {
+    pub id: String,
+    /// The other IDs of the model.
+    pub aliases: Vec<String>,
+    pub capabilities: model_list::ModelCapabilities,
+    /// The maximum number of tokens of the model context window.
+    pub max_context_length: Option<u32>,
+    /// The deprecation date of the model, if deprecated.
+    pub deprecation: Option<String>,
+    /// The model replacing this one once deprecated.
+    pub deprecation_replacement_model: Option<String>,
+}
+impl ModelInfo {
+    /// Returns whether the model supports the `feature`.
+    pub fn supports(&self, feature: ModelFeature) -> bool {
+        let capabilities = &self.capabilities;
+        match feature {
+            ModelFeature::Chat => capabilities.completion_chat,
+            ModelFeature::Classification => capabilities.classification,
+            ModelFeature::Fim => capabilities.completion_fim,
+            ModelFeature::FineTuning => capabilities.fine_tuning,
+            ModelFeature::FunctionCalling => capabilities.function_calling,
+            // JSON mode is available to every chat model.
+            ModelFeature::JsonMode => capabilities.completion_chat,
+            ModelFeature::Vision => capabilities.vision,
+        }
+    }
+
+    /// Returns whether the model deprecation date is past or within the `period` from now.
+    ///
+    /// Models without a (parsable) deprecation date are never considered deprecated.
+    pub fn is_deprecated_within(&self, period: Duration) -> bool {
+        let deprecation_days = match self.deprecation.as_deref().and_then(parse_date_as_days) {
+            Some(deprecation_days) => deprecation_days,
+            None => return false,
+        };
+        let now_seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let warning_days = ((now_seconds + period.as_secs()) / 86400) as i64;
+
+        deprecation_days <= warning_days
+    }
+}
+impl From<&model_list::ModelListData> for ModelInfo {
+    fn from(model: &model_list::ModelListData) -> Self {
+        Self {
+            id: model.id.clone(),
+            aliases: model.aliases.clone(),
+            capabilities: model.capabilities.clone(),
+            max_context_length: model.max_context_length,
+            deprecation: model.deprecation.clone(),
+            deprecation_replacement_model: model.deprecation_replacement_model.clone(),
+        }
+    }
+}
+
+/// A feature a model may or may not support.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModelFeature {
+    Chat,
+    Classification,
+    Fim,
+    FineTuning,
+    FunctionCalling,
+    JsonMode,
+    Vision,
+}
+impl fmt::Display for ModelFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Chat => "chat completions",
+            Self::Classification => "classification",
+            Self::Fim => "fill-in-the-middle completions",
+            Self::FineTuning => "fine-tuning",
+            Self::FunctionCalling => "function calling",
+            Self::JsonMode => "JSON mode",
+            Self::Vision => "vision",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// A lookup table of the models capabilities.
+///
+/// The default registry is a built-in snapshot of the [constants::Model](crate::v1::constants::Model)
+/// capabilities. Prefer [Client::fetch_model_registry](crate::v1::client::Client::fetch_model_registry)
+/// to get the up-to-date capabilities of all the models available to your API key.
+#[derive(Clone, Debug)]
+pub struct ModelRegistry {
+    pub models: Vec<ModelInfo>,
+}
+impl ModelRegistry {
+    pub fn new(models: Vec<ModelInfo>) -> Self {
+        Self { models }
+    }
+
+    pub fn from_model_list(model_list: &model_list::ModelListResponse) -> Self {
+        Self::new(model_list.data.iter().map(ModelInfo::from).collect())
+    }
+
+    /// Returns the model matching the `model_id`, either by its ID or one of its aliases.
+    pub fn get(&self, model_id: &str) -> Option<&ModelInfo> {
+        self.models
+            .iter()
+            .find(|model| model.id == model_id)
+            .or_else(|| {
+                self.models
+                    .iter()
+                    .find(|model| model.aliases.iter().any(|alias| alias == model_id))
+            })
+    }
+
+    /// Returns the concrete model ID a `-latest` alias currently points to, or `model_id` as is
+    /// when it's not an alias or is unknown to the registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mistralai_client::v1::{
+    ///     model_list::ModelCapabilities,
+    ///     model_registry::{ModelInfo, ModelRegistry},
+    /// };
+    ///
+    /// let registry = ModelRegistry::new(vec![ModelInfo {
+    ///     id: "mistral-large-2411".to_string(),
+    ///     aliases: vec!["mistral-large-latest".to_string()],
+    ///     capabilities: ModelCapabilities::default(),
+    ///     max_context_length: None,
+    ///     deprecation: None,
+    ///     deprecation_replacement_model: None,
+    /// }]);
+    /// assert_eq!(registry.resolve("mistral-large-latest"), "mistral-large-2411");
+    /// ```
+    pub fn resolve(&self, model_id: &str) -> String {
+        if !model_id.ends_with("-latest") {
+            return model_id.to_string();
+        }
+
+        // The models listing may both include the alias as its own model (aliased by its
+        // concrete version) and the concrete version (aliased by the alias).
+        self.models
+            .iter()
+            .filter(|model| model.id == model_id || model.aliases.iter().any(|id| id == model_id))
+            .flat_map(|model| std::iter::once(&model.id).chain(model.aliases.iter()))
+            .find(|id| !id.ends_with("-latest"))
+            .cloned()
+            .unwrap_or_else(|| model_id.to_string())
+    }
+
+    /// Checks that the requested model supports the features used by the chat `request`.
+    ///
+    /// Models missing from the registry are always accepted.
+    pub fn check_chat_request(
+        &self,
+        request: &chat::ChatRequest,
+    ) -> Result<(), error::ModelCapabilityError> {
+        let mut features = vec![ModelFeature::Chat];
+        if request
+            .tools
+            .as_ref()
+            .is_some_and(|tools| !tools.is_empty())
+        {
+            features.push(ModelFeature::FunctionCalling);
+        }
+        if request
+            .response_format
+            .as_ref()
+            .is_some_and(|response_format| response_format.type_ != "text")
+        {
+            features.push(ModelFeature::JsonMode);
+        }
+        if request.messages.iter().any(has_image) {
+            features.push(ModelFeature::Vision);
+        }
+
+        self.check_features(request.model.as_str(), &features)
+    }
+
+    /// Checks that the requested model supports fill-in-the-middle completions.
+    ///
+    /// Models missing from the registry are always accepted.
+    pub fn check_fim_request(
+        &self,
+        request: &fim::FimRequest,
+    ) -> Result<(), error::ModelCapabilityError> {
+        self.check_features(request.model.as_str(), &[ModelFeature::Fim])
+    }
+
+    fn check_features(
+        &self,
+        model_id: &str,
+        features: &[ModelFeature],
+    ) -> Result<(), error::ModelCapabilityError> {
+        let model = match self.get(model_id) {
+            Some(model) => model,
+            None => return Ok(()),
+        };
+
+        match features.iter().find(|feature| !model.supports(**feature)) {
+            Some(feature) => Err(error::ModelCapabilityError::UnsupportedFeature {
+                model: model_id.to_string(),
+                feature: *feature,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+impl Default for ModelRegistry {
+    fn default() -> Self {
+        Self::new(get_default_models())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Resolution
+
+/// The parameters for the client-side resolution of `-latest` model aliases.
+///
+/// See [Client::set_model_resolution](crate::v1::client::Client::set_model_resolution) for more information.
+#[derive(Clone, Debug)]
+pub struct ModelResolutionParams {
+    /// How long the fetched models listing is reused before being fetched again.
+    ///
+    /// Defaults to 1 hour.
+    pub ttl: Duration,
+    /// How long before its deprecation date a model starts being warned about.
+    ///
+    /// Defaults to 30 days.
+    pub deprecation_warning_period: Duration,
+}
+impl Default for ModelResolutionParams {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(60 * 60),
+            deprecation_warning_period: Duration::from_secs(30 * 24 * 60 * 60),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ModelRegistryCache {
+    pub(crate) fetched_at: Instant,
+    pub(crate) registry: ModelRegistry,
+    /// The models already warned about since the listing was fetched.
+    pub(crate) warned_model_ids: HashSet<String>,
+}
+impl ModelRegistryCache {
+    pub(crate) fn new(registry: ModelRegistry) -> Self {
+        Self {
+            fetched_at: Instant::now(),
+            registry,
+            warned_model_ids: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn is_expired(&self, ttl: Duration) -> bool {
+        self.fetched_at.elapsed() > ttl
+    }
+
+    /// Returns the concrete model ID of `model_id`, warning (once) when it's (about to be) deprecated.
+    pub(crate) fn resolve(&mut self, model_id: &str, params: &ModelResolutionParams) -> String {
+        let resolved_model_id = self.registry.resolve(model_id);
+        if resolved_model_id != model_id {
+            debug!("Resolved model `{}` to `{}`.", model_id, resolved_model_id);
+        }
+
+        if let Some(model) = self.registry.get(&resolved_model_id) {
+            if model.is_deprecated_within(params.deprecation_warning_period)
+                && self.warned_model_ids.insert(resolved_model_id.clone())
+            {
+                warn!(
+                    "The `{}` model is deprecated on {}.{}",
+                    resolved_model_id,
+                    model.deprecation.as_deref().unwrap_or_default(),
+                    get_replacement_hint(model)
+                );
+            }
+        }
+
+        resolved_model_id
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+fn get_replacement_hint(model: &ModelInfo) -> String {
+    match &model.deprecation_replacement_model {
+        Some(replacement_model) => format!(" Use `{}` instead.", replacement_model),
+        None => String::new(),
+    }
+}
+
+/// Parses the date of an ISO 8601 date or date-time, as a number of days since the Unix epoch.
+fn parse_date_as_days(date: &str) -> Option<i64> {
+    let mut parts = date.get(0..10)?.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // See http://howardhinnant.github.io/date_algorithms.html#days_from_civil.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Some(era * 146097 + day_of_era - 719468)
+}
+
+fn has_image(message: &chat::ChatMessage) -> bool {
+    match &message.content {
+        chat::ChatMessageContent::Chunks(chunks) => chunks
+            .iter()
+            .any(|chunk| matches!(chunk, chat::ChatMessageContentChunk::ImageUrl { .. })),
+        _ => false,
+    }
+}
+
+/// Returns the built-in snapshot of the [constants::Model](crate::v1::constants::Model) capabilities.
+fn get_default_models() -> Vec<ModelInfo> {
+    vec![
+        get_default_model(
+            "open-mistral-7b",
+            32768,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                fine_tuning: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "open-mixtral-8x7b",
+            32768,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                fine_tuning: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "open-mixtral-8x22b",
+            65536,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                function_calling: true,
+                fine_tuning: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "mistral-tiny",
+            32768,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "mistral-small-latest",
+            131072,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                function_calling: true,
+                fine_tuning: true,
+                vision: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "mistral-medium-latest",
+            131072,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                function_calling: true,
+                fine_tuning: true,
+                vision: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "mistral-large-latest",
+            131072,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                function_calling: true,
+                fine_tuning: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "codestral-latest",
+            262144,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                completion_fim: true,
+                function_calling: true,
+                fine_tuning: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "magistral-small-latest",
+            131072,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                function_calling: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "magistral-medium-latest",
+            131072,
+            model_list::ModelCapabilities {
+                completion_chat: true,
+                function_calling: true,
+                ..Default::default()
+            },
+        ),
+        get_default_model(
+            "mistral-embed",
+            8192,
+            model_list::ModelCapabilities {
+                ..Default::default()
+            },
+        ),
+    ]
+}
+
+fn get_default_model(
+    id: &str,
+    max_context_length: u32,
+    capabilities: model_list::ModelCapabilities,
+) -> ModelInfo {
+    ModelInfo {
+        id: id.to_string(),
+        aliases: Vec::new(),
+        capabilities,
+        max_context_length: Some(max_context_length),
+        deprecation: None,
+        deprecation_replacement_model: None,
+    }
+}
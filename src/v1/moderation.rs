@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+use crate::v1::{chat, constants};
+
+// -----------------------------------------------------------------------------
+// Request
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ModerationRequest {
+    pub model: constants::Model,
+    /// The texts to moderate, each one getting its own result.
+    pub input: Vec<String>,
+}
+impl ModerationRequest {
+    pub fn new(model: constants::Model, input: Vec<String>) -> Self {
+        Self { model, input }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChatModerationRequest {
+    pub model: constants::Model,
+    /// The conversations to moderate, each one getting its own result.
+    ///
+    /// The last message of each conversation is moderated, in the context of the previous ones.
+    pub input: Vec<Vec<chat::ChatMessage>>,
+}
+impl ChatModerationRequest {
+    pub fn new(model: constants::Model, input: Vec<Vec<chat::ChatMessage>>) -> Self {
+        Self { model, input }
+    }
+}
+
+/// The parameters of [Client::chat_moderated](crate::v1::client::Client::chat_moderated).
+#[derive(Clone, Debug)]
+pub struct ModerationGuardParams {
+    /// The moderation model.
+    ///
+    /// Defaults to `Model::MistralModerationLatest`.
+    pub model: constants::Model,
+    /// Whether to moderate the conversation last message before sending the chat request.
+    ///
+    /// Defaults to `true`.
+    pub moderate_input: bool,
+    /// Whether to moderate the assistant message of the chat response.
+    ///
+    /// Defaults to `true`.
+    pub moderate_output: bool,
+    /// The score from which a category is flagged, per category.
+    /// The categories without threshold are flagged according to the API decision.
+    ///
+    /// Defaults to an empty map.
+    pub thresholds: HashMap<ModerationCategory, f32>,
+    /// What to do with flagged content.
+    ///
+    /// Defaults to `ModerationAction::Block`.
+    pub action: ModerationAction,
+}
+impl Default for ModerationGuardParams {
+    fn default() -> Self {
+        Self {
+            model: constants::Model::MistralModerationLatest,
+            moderate_input: true,
+            moderate_output: true,
+            thresholds: HashMap::new(),
+            action: ModerationAction::Block,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModerationAction {
+    /// Fail with a [ModerationError](crate::v1::error::ModerationError) when content is flagged.
+    Block,
+    /// Only report the flagged categories in the [ModeratedChatResponse].
+    Flag,
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModerationResponse {
+    pub id: String,
+    pub model: constants::Model,
+    /// The results, in the same order as the request input.
+    pub results: Vec<ModerationResult>,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl ModerationResponse {
+    /// Returns the flagged categories of all the results, sorted by name.
+    ///
+    /// See [ModerationResult::get_flagged_categories] for more information.
+    pub fn get_flagged_categories(
+        &self,
+        thresholds: &HashMap<ModerationCategory, f32>,
+    ) -> Vec<ModerationCategory> {
+        let mut flagged_categories: Vec<ModerationCategory> = self
+            .results
+            .iter()
+            .flat_map(|result| result.get_flagged_categories(thresholds))
+            .collect();
+        flagged_categories.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        flagged_categories.dedup();
+
+        flagged_categories
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ModerationResult {
+    /// Whether the API flags the content, per category.
+    pub categories: HashMap<ModerationCategory, bool>,
+    /// The probability of the content belonging to the category (between `0.0` and `1.0`), per category.
+    pub category_scores: HashMap<ModerationCategory, f32>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl ModerationResult {
+    /// Returns whether the API flags the content in any category.
+    pub fn is_flagged(&self) -> bool {
+        self.categories.values().any(|is_flagged| *is_flagged)
+    }
+
+    /// Returns the flagged categories, sorted by name.
+    ///
+    /// A category with a threshold is flagged when its score reaches the threshold,
+    /// any other one when the API flags it.
+    pub fn get_flagged_categories(
+        &self,
+        thresholds: &HashMap<ModerationCategory, f32>,
+    ) -> Vec<ModerationCategory> {
+        let mut flagged_categories: Vec<ModerationCategory> = self
+            .categories
+            .keys()
+            .chain(self.category_scores.keys())
+            .chain(thresholds.keys())
+            .filter(|category| match thresholds.get(category) {
+                Some(threshold) => self
+                    .category_scores
+                    .get(category)
+                    .is_some_and(|score| score >= threshold),
+                None => self.categories.get(category).copied().unwrap_or_default(),
+            })
+            .cloned()
+            .collect();
+        flagged_categories.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        flagged_categories.dedup();
+
+        flagged_categories
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
+pub enum ModerationCategory {
+    #[serde(rename = "sexual")]
+    Sexual,
+    #[serde(rename = "hate_and_discrimination")]
+    HateAndDiscrimination,
+    #[serde(rename = "violence_and_threats")]
+    ViolenceAndThreats,
+    #[serde(rename = "dangerous_and_criminal_content")]
+    DangerousAndCriminalContent,
+    #[serde(rename = "selfharm")]
+    Selfharm,
+    #[serde(rename = "health")]
+    Health,
+    #[serde(rename = "financial")]
+    Financial,
+    #[serde(rename = "law")]
+    Law,
+    #[serde(rename = "pii")]
+    Pii,
+    /// A category unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
+}
+impl ModerationCategory {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Sexual => "sexual",
+            Self::HateAndDiscrimination => "hate_and_discrimination",
+            Self::ViolenceAndThreats => "violence_and_threats",
+            Self::DangerousAndCriminalContent => "dangerous_and_criminal_content",
+            Self::Selfharm => "selfharm",
+            Self::Health => "health",
+            Self::Financial => "financial",
+            Self::Law => "law",
+            Self::Pii => "pii",
+            Self::Unknown(category) => category,
+        }
+    }
+}
+impl fmt::Display for ModerationCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ModeratedChatResponse {
+    pub response: chat::ChatResponse,
+    /// The flagged categories of the conversation last message (`ModerationAction::Flag` only).
+    pub input_flagged_categories: Vec<ModerationCategory>,
+    /// The flagged categories of the assistant message (`ModerationAction::Flag` only).
+    pub output_flagged_categories: Vec<ModerationCategory>,
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+/// Returns the categories as a comma-separated list, for error messages.
+pub(crate) fn join_categories(categories: &[ModerationCategory]) -> String {
+    categories
+        .iter()
+        .map(|category| category.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
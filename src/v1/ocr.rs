@@ -0,0 +1,301 @@
+use base64::Engine;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::v1::{chat, constants, error};
+
+// -----------------------------------------------------------------------------
+// Definitions
+
+/// The document to process.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum OcrDocument {
+    /// A PDF document (or any other supported document format) URL.
+    #[serde(rename = "document_url")]
+    DocumentUrl {
+        document_url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        document_name: Option<String>,
+    },
+    /// An image URL, which can also be a base64 data URL (`data:image/png;base64,...`).
+    #[serde(rename = "image_url")]
+    ImageUrl { image_url: String },
+    /// A file uploaded via [Client::upload_file](crate::v1::client::Client::upload_file)
+    /// with the `ocr` purpose.
+    #[serde(rename = "file")]
+    File { file_id: String },
+}
+impl OcrDocument {
+    pub fn new_document_url(document_url: &str) -> Self {
+        Self::DocumentUrl {
+            document_url: document_url.to_string(),
+            document_name: None,
+        }
+    }
+
+    pub fn new_image_url(image_url: &str) -> Self {
+        Self::ImageUrl {
+            image_url: image_url.to_string(),
+        }
+    }
+
+    pub fn new_file(file_id: &str) -> Self {
+        Self::File {
+            file_id: file_id.to_string(),
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Request
+
+/// The parameters for the OCR request.
+///
+/// See the [API documentation](https://docs.mistral.ai/api/#tag/ocr) for more information.
+///
+/// # Examples
+///
+/// ```
+/// use mistralai_client::v1::{chat::ResponseFormat, ocr::OcrParams};
+/// use schemars::JsonSchema;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, JsonSchema)]
+/// struct Invoice {
+///     number: String,
+///     total: f32,
+/// }
+///
+/// let params = OcrParams {
+///     document_annotation_format: Some(ResponseFormat::json_schema_for::<Invoice>(true)),
+///     include_image_base64: Some(true),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OcrParams {
+    /// The format of each extracted image annotation, usually a JSON Schema
+    /// (see [ResponseFormat::json_schema_for](chat::ResponseFormat::json_schema_for)).
+    ///
+    /// Defaults to `None`.
+    pub bbox_annotation_format: Option<chat::ResponseFormat>,
+    /// The format of the whole document annotation, usually a JSON Schema
+    /// (see [ResponseFormat::json_schema_for](chat::ResponseFormat::json_schema_for)).
+    ///
+    /// Defaults to `None`.
+    pub document_annotation_format: Option<chat::ResponseFormat>,
+    /// The maximum number of images to extract.
+    ///
+    /// Defaults to `None`.
+    pub image_limit: Option<u32>,
+    /// The minimum height and width (in pixels) of the images to extract.
+    ///
+    /// Defaults to `None`.
+    pub image_min_size: Option<u32>,
+    /// Whether to include the extracted images base64 data in the response.
+    /// It's required to decode or save them.
+    ///
+    /// Defaults to `None`.
+    pub include_image_base64: Option<bool>,
+    /// The indexes (starting from `0`) of the pages to process.
+    ///
+    /// Defaults to `None` (all pages).
+    pub pages: Option<Vec<u32>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OcrRequest {
+    pub model: constants::Model,
+    pub document: OcrDocument,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bbox_annotation_format: Option<chat::ResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_annotation_format: Option<chat::ResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_min_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_image_base64: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pages: Option<Vec<u32>>,
+}
+impl OcrRequest {
+    pub fn new(model: constants::Model, document: OcrDocument, options: Option<OcrParams>) -> Self {
+        let OcrParams {
+            bbox_annotation_format,
+            document_annotation_format,
+            image_limit,
+            image_min_size,
+            include_image_base64,
+            pages,
+        } = options.unwrap_or_default();
+
+        Self {
+            model,
+            document,
+
+            bbox_annotation_format,
+            document_annotation_format,
+            image_limit,
+            image_min_size,
+            include_image_base64,
+            pages,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Response
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OcrResponse {
+    pub model: constants::Model,
+    /// The processed pages, in the document order.
+    pub pages: Vec<OcrPage>,
+    /// The whole document annotation, as a JSON string matching `document_annotation_format`.
+    pub document_annotation: Option<String>,
+    pub usage_info: OcrUsageInfo,
+    /// The fields unknown to this client version (e.g. from newer API features).
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl OcrResponse {
+    /// Returns the markdown of all the pages, separated by a blank line.
+    pub fn get_markdown(&self) -> String {
+        self.pages
+            .iter()
+            .map(|page| page.markdown.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Deserializes the document annotation into `T`, returning `None` without annotation.
+    pub fn parse_document_annotation<T: DeserializeOwned>(
+        &self,
+    ) -> Option<Result<T, serde_json::Error>> {
+        self.document_annotation
+            .as_deref()
+            .map(serde_json::from_str::<T>)
+    }
+
+    /// Writes the extracted images of all the pages into the `directory`, named after their ID,
+    /// and returns their paths.
+    ///
+    /// See [OcrImage::save] for more information.
+    pub fn save_images<P: AsRef<Path>>(
+        &self,
+        directory: P,
+    ) -> Result<Vec<PathBuf>, error::OcrImageError> {
+        self.pages
+            .iter()
+            .flat_map(|page| page.images.iter())
+            .map(|image| image.save(directory.as_ref()))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OcrPage {
+    /// The page index in the document, starting from `0`.
+    pub index: u32,
+    /// The page content as markdown, referencing the extracted images by ID (e.g. `![img-0.jpeg](img-0.jpeg)`).
+    pub markdown: String,
+    #[serde(default)]
+    pub images: Vec<OcrImage>,
+    pub dimensions: Option<OcrPageDimensions>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OcrImage {
+    /// The image ID, which is also its file name (e.g. `img-0.jpeg`).
+    pub id: String,
+    /// The bounding box coordinates (in pixels) of the image in the page.
+    pub top_left_x: Option<u32>,
+    pub top_left_y: Option<u32>,
+    pub bottom_right_x: Option<u32>,
+    pub bottom_right_y: Option<u32>,
+    /// The image as a base64 data URL (`include_image_base64` only).
+    pub image_base64: Option<String>,
+    /// The image annotation, as a JSON string matching `bbox_annotation_format`.
+    pub image_annotation: Option<String>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+impl OcrImage {
+    /// Decodes the image base64 data, with or without a data URL prefix.
+    pub fn decode(&self) -> Result<Vec<u8>, error::OcrImageError> {
+        let image_base64 = self
+            .image_base64
+            .as_deref()
+            .ok_or_else(|| error::OcrImageError::MissingData(self.id.clone()))?;
+        let data = match image_base64.split_once(";base64,") {
+            Some((prefix, data)) if prefix.starts_with("data:") => data,
+            _ => image_base64,
+        };
+
+        base64::engine::general_purpose::STANDARD
+            .decode(data.trim())
+            .map_err(|error| error::OcrImageError::InvalidData {
+                id: self.id.clone(),
+                message: error.to_string(),
+            })
+    }
+
+    /// Writes the decoded image into the `directory`, named after its ID, and returns its path.
+    ///
+    /// The directory is created when it doesn't exist yet.
+    pub fn save<P: AsRef<Path>>(&self, directory: P) -> Result<PathBuf, error::OcrImageError> {
+        let bytes = self.decode()?;
+        let to_write_error = |error: std::io::Error| error::OcrImageError::UnwritableFile {
+            id: self.id.clone(),
+            message: error.to_string(),
+        };
+
+        // The ID comes from the API: keep its file name only so that it can't escape the directory.
+        let file_name = Path::new(&self.id).file_name().ok_or_else(|| {
+            error::OcrImageError::UnwritableFile {
+                id: self.id.clone(),
+                message: "Invalid image ID for a file name.".to_string(),
+            }
+        })?;
+        fs::create_dir_all(directory.as_ref()).map_err(to_write_error)?;
+        let path = directory.as_ref().join(file_name);
+        fs::write(&path, bytes).map_err(to_write_error)?;
+
+        Ok(path)
+    }
+
+    /// Deserializes the image annotation into `T`, returning `None` without annotation.
+    pub fn parse_annotation<T: DeserializeOwned>(&self) -> Option<Result<T, serde_json::Error>> {
+        self.image_annotation
+            .as_deref()
+            .map(serde_json::from_str::<T>)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct OcrPageDimensions {
+    pub dpi: u32,
+    pub height: u32,
+    pub width: u32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OcrUsageInfo {
+    pub pages_processed: u32,
+    pub doc_size_bytes: Option<u64>,
+    /// The fields unknown to this client version.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
@@ -0,0 +1,725 @@
+use async_trait::async_trait;
+use log::{debug, warn};
+use serde_json::{json, Map, Value};
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+    time::Duration,
+};
+
+use crate::v1::{client, error, tool, utils};
+
+/// The name of the tool argument holding the operation request body.
+pub const REQUEST_BODY_ARGUMENT: &str = "body";
+
+const HTTP_METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+/// Maximum number of nested `$ref` followed when inlining schemas, to protect against recursive ones.
+const MAX_SCHEMA_DEPTH: usize = 16;
+/// Maximum length of a function name accepted by the API.
+const MAX_FUNCTION_NAME_LENGTH: usize = 64;
+
+// -----------------------------------------------------------------------------
+// Definitions
+
+/// The parameters used to generate tools from an OpenAPI 3 document.
+#[derive(Clone, Debug)]
+pub struct OpenApiToolsParams {
+    /// The base URL the operations are called against.
+    ///
+    /// Defaults to the first absolute URL declared in the document `servers`.
+    pub base_url: Option<String>,
+    /// Headers sent along every operation call, e.g. `Authorization`.
+    ///
+    /// Defaults to none.
+    pub headers: HashMap<String, String>,
+    /// Only keep the operations having at least one of these tags.
+    ///
+    /// Defaults to `None` (no filtering).
+    pub tags: Option<Vec<String>>,
+    /// Only keep the operations having one of these `operationId`.
+    ///
+    /// Defaults to `None` (no filtering).
+    pub operation_ids: Option<Vec<String>>,
+    /// The maximum duration of an operation call.
+    ///
+    /// Defaults to 120 seconds.
+    pub timeout: Duration,
+}
+impl Default for OpenApiToolsParams {
+    fn default() -> Self {
+        Self {
+            base_url: None,
+            headers: HashMap::new(),
+            tags: None,
+            operation_ids: None,
+            timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+/// The tools generated from an OpenAPI 3 document, one per operation.
+///
+/// # Examples
+///
+/// ```no_run
+/// use mistralai_client::v1::{
+///     client::Client,
+///     openapi::{OpenApiTools, OpenApiToolsParams},
+/// };
+///
+/// let params = OpenApiToolsParams {
+///     base_url: Some("https://internal.example.org/api".to_string()),
+///     headers: [("Authorization".to_string(), "Bearer secret".to_string())].into(),
+///     tags: Some(vec!["weather".to_string()]),
+///     ..Default::default()
+/// };
+/// let openapi_tools = OpenApiTools::from_file("./openapi.yaml", params).unwrap();
+///
+/// let mut client = Client::new(None, None, None, None).unwrap();
+/// openapi_tools.register_functions(&mut client);
+/// let tools = openapi_tools.tools();
+/// ```
+#[derive(Clone, Debug)]
+pub struct OpenApiTools {
+    pub operations: Vec<OpenApiOperation>,
+    /// The selected operations that couldn't be mapped into a tool, e.g. with a non-JSON request body.
+    pub skipped_operations: Vec<OpenApiSkippedOperation>,
+}
+impl OpenApiTools {
+    /// Generates the tools from an OpenAPI 3 document file, either in JSON or YAML.
+    pub fn from_file<P: AsRef<Path>>(
+        path: P,
+        params: OpenApiToolsParams,
+    ) -> Result<Self, error::OpenApiError> {
+        let document = fs::read_to_string(path)
+            .map_err(|error| error::OpenApiError::UnreadableDocument(error.to_string()))?;
+
+        Self::from_document(&document, params)
+    }
+
+    /// Generates the tools from an OpenAPI 3 document content, either in JSON or YAML.
+    pub fn from_document(
+        document: &str,
+        params: OpenApiToolsParams,
+    ) -> Result<Self, error::OpenApiError> {
+        let document = match serde_json::from_str::<Value>(document) {
+            Ok(document) => document,
+            Err(_) => serde_norway::from_str::<Value>(document)
+                .map_err(|error| error::OpenApiError::InvalidDocument(error.to_string()))?,
+        };
+        let is_openapi_3 = document
+            .get("openapi")
+            .and_then(Value::as_str)
+            .is_some_and(|version| version.starts_with('3'));
+        if !is_openapi_3 {
+            return Err(error::OpenApiError::InvalidDocument(
+                "only OpenAPI 3 documents are supported.".to_string(),
+            ));
+        }
+
+        let base_url = match params
+            .base_url
+            .clone()
+            .or_else(|| get_server_url(&document))
+        {
+            Some(base_url) => base_url.trim_end_matches('/').to_string(),
+            None => return Err(error::OpenApiError::MissingBaseUrl),
+        };
+
+        let paths = match document.get("paths").and_then(Value::as_object) {
+            Some(paths) => paths,
+            None => {
+                return Err(error::OpenApiError::InvalidDocument(
+                    "missing `paths` object.".to_string(),
+                ))
+            }
+        };
+
+        let mut operations = Vec::new();
+        let mut skipped_operations = Vec::new();
+        let mut function_names = HashSet::new();
+        let context = OpenApiDocumentContext {
+            document: &document,
+            base_url: &base_url,
+            params: &params,
+            // Shared by all the functions, so that their calls reuse the same connection pool.
+            http_client: reqwest::Client::new(),
+        };
+        for (path, path_item) in paths {
+            let path_item = resolve_reference(&document, path_item);
+            let path_parameters = path_item
+                .get("parameters")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+
+            for method in HTTP_METHODS {
+                let operation = match path_item.get(method) {
+                    Some(operation) => operation,
+                    None => continue,
+                };
+                if !is_operation_selected(operation, &params) {
+                    continue;
+                }
+
+                match build_operation(&context, path, method, operation, &path_parameters) {
+                    Ok(mut operation) => {
+                        let name = get_unique_function_name(
+                            operation.function.name.clone(),
+                            &mut function_names,
+                        );
+                        operation.tool.function.name = name.clone();
+                        operation.function.name = name;
+                        operations.push(operation);
+                    }
+                    Err(reason) => {
+                        warn!(
+                            "Skipping `{} {}` operation: {}",
+                            method.to_uppercase(),
+                            path,
+                            reason
+                        );
+                        skipped_operations.push(OpenApiSkippedOperation {
+                            method: method.to_uppercase(),
+                            path: path.to_string(),
+                            reason,
+                        });
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            operations,
+            skipped_operations,
+        })
+    }
+
+    /// Returns the generated tools, to be passed to [crate::v1::chat::ChatParams] `tools`.
+    pub fn tools(&self) -> Vec<tool::Tool> {
+        self.operations
+            .iter()
+            .map(|operation| operation.tool.clone())
+            .collect()
+    }
+
+    /// Registers every generated function in the client, under its tool name.
+    pub fn register_functions(&self, client: &mut client::Client) {
+        for operation in &self.operations {
+            client.register_function(
+                operation.function.name.clone(),
+                Box::new(operation.function.clone()),
+            );
+        }
+    }
+}
+
+/// An OpenAPI operation left out of the generated tools.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenApiSkippedOperation {
+    /// The uppercase HTTP method, e.g. `POST`.
+    pub method: String,
+    pub path: String,
+    /// Why the operation couldn't be mapped into a tool.
+    pub reason: String,
+}
+
+/// A single OpenAPI operation mapped into a tool and the function calling it.
+#[derive(Clone, Debug)]
+pub struct OpenApiOperation {
+    pub tool: tool::Tool,
+    pub function: OpenApiFunction,
+}
+
+/// A [tool::Function] performing the HTTP call of an OpenAPI operation.
+///
+/// Path, query and header parameters are read from the top-level tool call arguments by name,
+/// while the request body is read from the [REQUEST_BODY_ARGUMENT] argument.
+///
+/// The function result is a `Result<String, ApiError>` holding the response body.
+#[derive(Clone, Debug)]
+pub struct OpenApiFunction {
+    /// The tool function name.
+    pub name: String,
+    pub method: reqwest::Method,
+    /// The operation URL, with its path parameters as `{name}` placeholders.
+    pub url: String,
+    pub parameters: Vec<OpenApiFunctionParameter>,
+    /// Whether the operation accepts a JSON request body.
+    pub has_body: bool,
+    /// Headers sent along every call.
+    pub headers: HashMap<String, String>,
+    /// The maximum duration of a call.
+    pub timeout: Duration,
+    http_client: reqwest::Client,
+}
+impl OpenApiFunction {
+    /// Performs the HTTP call with the JSON-encoded tool call arguments and returns the response body.
+    pub async fn call(&self, arguments: &str) -> Result<String, error::ApiError> {
+        let arguments = if arguments.trim().is_empty() {
+            Map::new()
+        } else {
+            serde_json::from_str::<Map<String, Value>>(arguments).map_err(|error| {
+                error::ApiError {
+                    message: format!("Invalid `{}` arguments: {}", self.name, error),
+                    kind: error::ApiErrorKind::Other,
+                }
+            })?
+        };
+
+        let mut url = self.url.clone();
+        let mut query: Vec<(String, String)> = Vec::new();
+        let mut headers = self.headers.clone();
+        for parameter in &self.parameters {
+            let value = match arguments.get(&parameter.argument) {
+                Some(Value::Null) | None => {
+                    if parameter.location == OpenApiParameterLocation::Path {
+                        return Err(error::ApiError {
+                            message: format!(
+                                "Missing `{}` path parameter in `{}` arguments.",
+                                parameter.argument, self.name
+                            ),
+                            kind: error::ApiErrorKind::Other,
+                        });
+                    }
+
+                    continue;
+                }
+                Some(value) => value,
+            };
+
+            match parameter.location {
+                OpenApiParameterLocation::Path => {
+                    let placeholder = format!("{{{}}}", parameter.name);
+                    url = url.replace(&placeholder, &encode_path_segment(&to_plain_string(value)));
+                }
+                OpenApiParameterLocation::Query => match value {
+                    Value::Array(items) => query.extend(
+                        items
+                            .iter()
+                            .map(|item| (parameter.name.clone(), to_plain_string(item))),
+                    ),
+                    _ => query.push((parameter.name.clone(), to_plain_string(value))),
+                },
+                OpenApiParameterLocation::Header => {
+                    headers.insert(parameter.name.clone(), to_plain_string(value));
+                }
+            }
+        }
+
+        debug!("Request URL: {}", url);
+
+        let mut request_builder = self
+            .http_client
+            .request(self.method.clone(), url)
+            .query(&query)
+            .timeout(self.timeout);
+        for (name, value) in &headers {
+            request_builder = request_builder.header(name, value);
+        }
+        if self.has_body {
+            if let Some(body) = arguments.get(REQUEST_BODY_ARGUMENT) {
+                utils::debug_pretty_json_from_struct("Request Body", body);
+
+                request_builder = request_builder.json(body);
+            }
+        }
+
+        let response = request_builder
+            .send()
+            .await
+            .map_err(error::ApiError::from_reqwest_error)?;
+        let response_status = response.status();
+        let response_body = response.text().await.unwrap_or_default();
+        debug!("Response Status: {}", &response_status);
+        utils::debug_pretty_json_from_string("Response Data", &response_body);
+
+        if response_status.is_success() {
+            Ok(response_body)
+        } else {
+            Err(error::ApiError {
+                message: format!("{}: {}", response_status, response_body),
+                kind: error::ApiErrorKind::Status(response_status.as_u16()),
+            })
+        }
+    }
+}
+#[async_trait]
+impl tool::Function for OpenApiFunction {
+    async fn execute(&self, arguments: String) -> Box<dyn Any + Send> {
+        Box::new(self.call(&arguments).await)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OpenApiFunctionParameter {
+    pub name: String,
+    pub location: OpenApiParameterLocation,
+    /// The tool call argument holding the parameter value: its `name`, unless another parameter
+    /// (or the request body) already uses it, in which case it's prefixed by its location (e.g. `query_id`).
+    pub argument: String,
+}
+
+/// Where an operation parameter is sent.
+///
+/// Cookie parameters are not supported.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OpenApiParameterLocation {
+    Path,
+    Query,
+    Header,
+}
+
+// -----------------------------------------------------------------------------
+// Helpers
+
+/// What the operations of a document share.
+struct OpenApiDocumentContext<'a> {
+    document: &'a Value,
+    base_url: &'a str,
+    params: &'a OpenApiToolsParams,
+    http_client: reqwest::Client,
+}
+
+fn build_operation(
+    context: &OpenApiDocumentContext,
+    path: &str,
+    method: &str,
+    operation: &Value,
+    path_parameters: &[Value],
+) -> Result<OpenApiOperation, String> {
+    let document = context.document;
+    let name = get_function_name(operation, method, path);
+    let description = get_function_description(operation, method, path);
+
+    // Operation parameters override path-level ones sharing the same name and location.
+    let mut parameters: Vec<Value> = Vec::new();
+    let operation_parameters = operation
+        .get("parameters")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for parameter in path_parameters.iter().chain(operation_parameters.iter()) {
+        let parameter = resolve_reference(document, parameter).clone();
+        parameters.retain(|existing| {
+            existing.get("name") != parameter.get("name")
+                || existing.get("in") != parameter.get("in")
+        });
+        parameters.push(parameter);
+    }
+
+    let mut properties = Map::new();
+    let mut required: Vec<String> = Vec::new();
+    let mut function_parameters: Vec<OpenApiFunctionParameter> = Vec::new();
+    let mut arguments = HashSet::new();
+    if operation.get("requestBody").is_some() {
+        arguments.insert(REQUEST_BODY_ARGUMENT.to_string());
+    }
+    for parameter in parameters {
+        let name = match parameter.get("name").and_then(Value::as_str) {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        let (location, location_name) = match parameter.get("in").and_then(Value::as_str) {
+            Some(location_name @ "path") => (OpenApiParameterLocation::Path, location_name),
+            Some(location_name @ "query") => (OpenApiParameterLocation::Query, location_name),
+            Some(location_name @ "header") => (OpenApiParameterLocation::Header, location_name),
+            _ => continue,
+        };
+        let argument = get_unique_argument_name(&name, location_name, &mut arguments);
+        if argument != name {
+            warn!(
+                "Renaming the `{}` {} parameter of `{} {}` into `{}`, since its name is already used.",
+                name,
+                location_name,
+                method.to_uppercase(),
+                path,
+                argument
+            );
+        }
+
+        let mut schema = parameter
+            .get("schema")
+            .map(|schema| inline_schema(document, schema, 0))
+            .unwrap_or_else(|| json!({ "type": "string" }));
+        if let (Some(schema), Some(description)) =
+            (schema.as_object_mut(), parameter.get("description"))
+        {
+            schema
+                .entry("description")
+                .or_insert_with(|| description.clone());
+        }
+
+        let is_required = location == OpenApiParameterLocation::Path
+            || parameter
+                .get("required")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+        if is_required {
+            required.push(argument.clone());
+        }
+
+        properties.insert(argument.clone(), schema);
+        function_parameters.push(OpenApiFunctionParameter {
+            name,
+            location,
+            argument,
+        });
+    }
+
+    let mut has_body = false;
+    if let Some(request_body) = operation.get("requestBody") {
+        let request_body = resolve_reference(document, request_body);
+        let content = request_body
+            .get("content")
+            .and_then(Value::as_object)
+            .ok_or_else(|| "the request body has no `content`.".to_string())?;
+        let (_, media_type) = content
+            .iter()
+            .find(|(content_type, _)| {
+                content_type.starts_with("application/json") || content_type.ends_with("+json")
+            })
+            .ok_or_else(|| {
+                format!(
+                    "only JSON request bodies are supported, not `{}`.",
+                    content.keys().cloned().collect::<Vec<_>>().join("`, `")
+                )
+            })?;
+
+        let mut schema = media_type
+            .get("schema")
+            .map(|schema| inline_schema(document, schema, 0))
+            .unwrap_or_else(|| json!({ "type": "object" }));
+        if let (Some(schema), Some(description)) =
+            (schema.as_object_mut(), request_body.get("description"))
+        {
+            schema
+                .entry("description")
+                .or_insert_with(|| description.clone());
+        }
+
+        if request_body
+            .get("required")
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+        {
+            required.push(REQUEST_BODY_ARGUMENT.to_string());
+        }
+
+        properties.insert(REQUEST_BODY_ARGUMENT.to_string(), schema);
+        has_body = true;
+    }
+
+    let parameters_schema = json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    });
+
+    Ok(OpenApiOperation {
+        tool: tool::Tool::new_with_schema(name.clone(), description, parameters_schema),
+        function: OpenApiFunction {
+            name,
+            method: reqwest::Method::from_bytes(method.to_uppercase().as_bytes())
+                .unwrap_or(reqwest::Method::GET),
+            url: format!("{}{}", context.base_url, path),
+            parameters: function_parameters,
+            has_body,
+            headers: context.params.headers.clone(),
+            timeout: context.params.timeout,
+            http_client: context.http_client.clone(),
+        },
+    })
+}
+
+fn is_operation_selected(operation: &Value, params: &OpenApiToolsParams) -> bool {
+    if let Some(tags) = &params.tags {
+        let operation_tags = operation
+            .get("tags")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        let has_tag = operation_tags
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|tag| tags.iter().any(|selected_tag| selected_tag == tag));
+        if !has_tag {
+            return false;
+        }
+    }
+
+    if let Some(operation_ids) = &params.operation_ids {
+        let operation_id = operation.get("operationId").and_then(Value::as_str);
+        let has_operation_id = operation_id.is_some_and(|operation_id| {
+            operation_ids
+                .iter()
+                .any(|selected_id| selected_id == operation_id)
+        });
+        if !has_operation_id {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn get_server_url(document: &Value) -> Option<String> {
+    document
+        .get("servers")
+        .and_then(Value::as_array)?
+        .iter()
+        .filter_map(|server| server.get("url").and_then(Value::as_str))
+        .find(|url| url.starts_with("http://") || url.starts_with("https://"))
+        .map(str::to_string)
+}
+
+fn get_function_name(operation: &Value, method: &str, path: &str) -> String {
+    let raw_name = match operation.get("operationId").and_then(Value::as_str) {
+        Some(operation_id) => operation_id.to_string(),
+        None => format!("{}_{}", method, path),
+    };
+
+    let mut name = String::new();
+    for character in raw_name.chars() {
+        let character = if character.is_ascii_alphanumeric() || character == '-' {
+            character
+        } else {
+            '_'
+        };
+        // Collapse consecutive separators produced by path slashes and braces.
+        if character == '_' && name.ends_with('_') {
+            continue;
+        }
+        name.push(character);
+    }
+
+    name.trim_matches('_')
+        .chars()
+        .take(MAX_FUNCTION_NAME_LENGTH)
+        .collect()
+}
+
+/// Suffixes the name with a counter (e.g. `getUser_2`) when another operation already uses it,
+/// since sanitizing and truncating can map different operations to the same name.
+fn get_unique_function_name(name: String, function_names: &mut HashSet<String>) -> String {
+    let mut unique_name = name.clone();
+    let mut counter = 2;
+    while function_names.contains(&unique_name) {
+        let suffix = format!("_{}", counter);
+        let prefix: String = name
+            .chars()
+            .take(MAX_FUNCTION_NAME_LENGTH - suffix.len())
+            .collect();
+        unique_name = format!("{}{}", prefix, suffix);
+        counter += 1;
+    }
+    function_names.insert(unique_name.clone());
+
+    unique_name
+}
+
+/// Returns the tool call argument name of a parameter, prefixing it by its location
+/// (and suffixing it with a counter if needed) when another argument already uses its name.
+fn get_unique_argument_name(
+    name: &str,
+    location_name: &str,
+    arguments: &mut HashSet<String>,
+) -> String {
+    let mut argument = name.to_string();
+    let mut counter = 2;
+    while arguments.contains(&argument) {
+        argument = match counter {
+            2 => format!("{}_{}", location_name, name),
+            _ => format!("{}_{}_{}", location_name, name, counter - 1),
+        };
+        counter += 1;
+    }
+    arguments.insert(argument.clone());
+
+    argument
+}
+
+fn get_function_description(operation: &Value, method: &str, path: &str) -> String {
+    let summary = operation.get("summary").and_then(Value::as_str);
+    let description = operation.get("description").and_then(Value::as_str);
+
+    match (summary, description) {
+        (Some(summary), Some(description)) => format!("{}\n\n{}", summary, description),
+        (Some(text), None) | (None, Some(text)) => text.to_string(),
+        (None, None) => format!("{} {}", method.to_uppercase(), path),
+    }
+}
+
+/// Follows a local `$ref` (e.g. `#/components/parameters/Id`), if any.
+fn resolve_reference<'a>(document: &'a Value, value: &'a Value) -> &'a Value {
+    let mut value = value;
+    for _ in 0..MAX_SCHEMA_DEPTH {
+        match value
+            .get("$ref")
+            .and_then(Value::as_str)
+            .and_then(|reference| reference.strip_prefix('#'))
+            .and_then(|pointer| document.pointer(pointer))
+        {
+            Some(referenced_value) => value = referenced_value,
+            None => break,
+        }
+    }
+
+    value
+}
+
+/// Returns a copy of the schema with all its local `$ref` inlined.
+fn inline_schema(document: &Value, schema: &Value, depth: usize) -> Value {
+    if depth > MAX_SCHEMA_DEPTH {
+        return json!({ "type": "object" });
+    }
+
+    match schema {
+        Value::Object(object) => match object.get("$ref").and_then(Value::as_str) {
+            Some(reference) => match reference
+                .strip_prefix('#')
+                .and_then(|pointer| document.pointer(pointer))
+            {
+                Some(referenced_schema) => inline_schema(document, referenced_schema, depth + 1),
+                // External references can't be resolved offline.
+                None => json!({}),
+            },
+            None => Value::Object(
+                object
+                    .iter()
+                    .map(|(key, value)| (key.clone(), inline_schema(document, value, depth)))
+                    .collect(),
+            ),
+        },
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| inline_schema(document, item, depth))
+                .collect(),
+        ),
+        _ => schema.clone(),
+    }
+}
+
+fn to_plain_string(value: &Value) -> String {
+    match value {
+        Value::String(string) => string.clone(),
+        _ => value.to_string(),
+    }
+}
+
+fn encode_path_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -7,7 +7,15 @@ use std::{any::Any, collections::HashMap, fmt::Debug};
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct ToolCall {
+    /// The tool call ID, to be referenced by the `tool_call_id` of the tool result message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<ToolType>,
     pub function: ToolCallFunction,
+    /// The position of this tool call within the message, mostly useful in streamed responses.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -47,12 +55,28 @@ impl Tool {
             required: property_names,
         };
 
+        Self::new_with_schema(
+            function_name,
+            function_description,
+            serde_json::to_value(parameters).unwrap_or_default(),
+        )
+    }
+
+    /// Constructs a new `Tool` from a raw JSON Schema describing the function parameters.
+    ///
+    /// This is useful when the parameters can't be expressed as a flat list of [ToolFunctionParameter],
+    /// e.g. with nested objects, arrays or optional properties.
+    pub fn new_with_schema(
+        function_name: String,
+        function_description: String,
+        function_parameters_schema: serde_json::Value,
+    ) -> Self {
         Self {
             r#type: ToolType::Function,
             function: ToolFunction {
                 name: function_name,
                 description: function_description,
-                parameters,
+                parameters: function_parameters_schema,
             },
         }
     }
@@ -63,9 +87,9 @@ impl Tool {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ToolFunction {
-    name: String,
+    pub(crate) name: String,
     description: String,
-    parameters: ToolFunctionParameters,
+    parameters: serde_json::Value,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -110,9 +134,13 @@ pub enum ToolFunctionParameterType {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[non_exhaustive]
 pub enum ToolType {
     #[serde(rename = "function")]
     Function,
+    /// A tool type unknown to this client version.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 /// An enum representing how functions should be called.
@@ -127,13 +155,41 @@ pub enum ToolChoice {
     /// The model won't call a function and will generate a message instead.
     #[serde(rename = "none")]
     None,
+    /// The model is forced to call at least one function.
+    #[serde(rename = "required")]
+    Required,
+    /// The model is forced to call this specific function.
+    #[serde(untagged)]
+    Function(ToolChoiceFunction),
+}
+impl ToolChoice {
+    /// Forces the model to call the function named `function_name`.
+    pub fn new_function(function_name: &str) -> Self {
+        Self::Function(ToolChoiceFunction {
+            r#type: ToolType::Function,
+            function: ToolChoiceFunctionName {
+                name: function_name.to_string(),
+            },
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ToolChoiceFunction {
+    pub r#type: ToolType,
+    pub function: ToolChoiceFunctionName,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ToolChoiceFunctionName {
+    pub name: String,
 }
 
 // -----------------------------------------------------------------------------
 // Custom
 
 #[async_trait]
-pub trait Function: Send {
+pub trait Function: Send + Sync {
     async fn execute(&self, arguments: String) -> Box<dyn Any + Send>;
 }
 
@@ -1,10 +1,10 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use log::debug;
 use serde::Serialize;
 
 pub fn prettify_json_string(json: &String) -> String {
-    match serde_json::from_str::<serde_json::Value>(&json) {
+    match serde_json::from_str::<serde_json::Value>(json) {
         Ok(json_value) => {
             serde_json::to_string_pretty(&json_value).unwrap_or_else(|_| json.to_owned())
         }
@@ -19,14 +19,66 @@ pub fn prettify_json_struct<T: Debug + Serialize>(value: T) -> String {
     }
 }
 
-pub fn debug_pretty_json_from_string(label: &str, json: &String) -> () {
+pub fn debug_pretty_json_from_string(label: &str, json: &String) {
     let pretty_json = prettify_json_string(json);
 
     debug!("{label}: {}", pretty_json);
 }
 
-pub fn debug_pretty_json_from_struct<T: Debug + Serialize>(label: &str, value: &T) -> () {
+pub fn debug_pretty_json_from_struct<T: Debug + Serialize>(label: &str, value: &T) {
     let pretty_json = prettify_json_struct(value);
 
     debug!("{label}: {}", pretty_json);
 }
+
+/// Serializes the `params` fields as a URL query string (including the leading `?`),
+/// skipping `null` values and repeating the keys of array values.
+pub fn get_query_string<T: Serialize>(params: &T) -> String {
+    let fields = match serde_json::to_value(params) {
+        Ok(serde_json::Value::Object(fields)) => fields,
+        _ => return String::new(),
+    };
+
+    let mut url = reqwest::Url::parse("http://localhost").unwrap();
+    {
+        let mut query_pairs = url.query_pairs_mut();
+        for (key, value) in fields {
+            let values = match value {
+                serde_json::Value::Array(values) => values,
+                value => vec![value],
+            };
+            for value in values {
+                match value {
+                    serde_json::Value::Null => {}
+                    serde_json::Value::String(value) => {
+                        query_pairs.append_pair(&key, &value);
+                    }
+                    value => {
+                        query_pairs.append_pair(&key, &value.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    match url.query() {
+        Some(query) if !query.is_empty() => format!("?{}", query),
+        _ => String::new(),
+    }
+}
+
+/// Returns the exponential backoff delay following the `interval` one, capped at `max_interval`.
+///
+/// Unlike `Duration::mul_f64()`, it doesn't panic on negative, NaN or overflowing multipliers,
+/// treating the ones below `1.0` (or NaN) as `1.0`.
+pub(crate) fn get_next_backoff_interval(
+    interval: Duration,
+    multiplier: f64,
+    max_interval: Duration,
+) -> Duration {
+    let multiplier = if multiplier >= 1.0 { multiplier } else { 1.0 };
+
+    Duration::try_from_secs_f64(interval.as_secs_f64() * multiplier)
+        .unwrap_or(max_interval)
+        .min(max_interval)
+}
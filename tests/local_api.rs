@@ -0,0 +1,85 @@
+// Each test crate including this module only uses some of its functions.
+#![allow(dead_code)]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// What the local API received, shared with its thread.
+pub type Received<T> = Arc<Mutex<Vec<T>>>;
+
+/// Starts a local API answering the requests with the `responses` (status code and JSON body)
+/// in order, repeating the last one, and returns its URL and the `METHOD /path (Authorization)`
+/// of the received requests.
+pub fn start_local_api(responses: Vec<(u16, serde_json::Value)>) -> (String, Received<String>) {
+    let (url, requests, _) = start_local_api_with_bodies(responses);
+
+    (url, requests)
+}
+
+/// Same as [start_local_api], also returning the (JSON if possible) bodies of the received requests.
+pub fn start_local_api_with_bodies(
+    responses: Vec<(u16, serde_json::Value)>,
+) -> (String, Received<String>, Received<serde_json::Value>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+
+    let received_requests = requests.clone();
+    let received_bodies = bodies.clone();
+    thread::spawn(move || {
+        for (index, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut authorization = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                let (name, value) = header.split_once(": ").unwrap();
+                match name.to_lowercase().as_str() {
+                    "authorization" => authorization = value.to_string(),
+                    "content-length" => content_length = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut request_line = request_line.split_whitespace();
+            received_requests.lock().unwrap().push(format!(
+                "{} {} ({})",
+                request_line.next().unwrap(),
+                request_line.next().unwrap(),
+                authorization
+            ));
+            received_bodies
+                .lock()
+                .unwrap()
+                .push(serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null));
+
+            let (status_code, response_body) = &responses[index.min(responses.len() - 1)];
+            let response_body = response_body.to_string();
+            write!(
+                stream,
+                "HTTP/1.1 {} Local\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status_code,
+                response_body.len(),
+                response_body
+            )
+            .unwrap();
+        }
+    });
+
+    (url, requests, bodies)
+}
@@ -0,0 +1,246 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    batch::{BatchEndpoint, BatchOutputLine},
+    batch_runner::{
+        get_batch_output_progress, parse_batch_input, BatchRunReport, BatchRunnerParams,
+    },
+    client::Client,
+    tool::Function,
+};
+use serde_json::json;
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+mod local_api;
+
+#[test]
+fn test_parse_batch_input() {
+    let input = r#"{"custom_id": "paris", "body": {"messages": [{"role": "user", "content": "Describe Paris."}]}}
+
+{"custom_id": "lyon", "body": {"messages": [{"role": "user", "content": "Describe Lyon."}]}}
+"#;
+    let input_lines = parse_batch_input(input).unwrap();
+
+    expect!(input_lines.len()).to_be(2);
+    expect!(input_lines[1].custom_id.clone()).to_be("lyon".to_string());
+    expect!(input_lines[1].body["messages"][0]["content"].clone()).to_be(json!("Describe Lyon."));
+
+    let input = r#"{"custom_id": "paris", "body": {}}
+{"custom_id": "paris", "body": {}}"#;
+    expect!(parse_batch_input(input).unwrap_err())
+        .to_be("Invalid batch input line 2: the `paris` custom ID is used twice.".to_string());
+
+    expect!(parse_batch_input(r#"{"body": {}}"#).is_err()).to_be(true);
+}
+
+#[test]
+fn test_get_batch_output_progress() {
+    let output = r#"{"custom_id": "paris", "response": {"status_code": 200, "body": {}}, "error": null}
+{"custom_id": "lyon", "response": null, "error": {"message": "Timeout"}}
+"#;
+    let progress = get_batch_output_progress(output);
+
+    expect!(progress.completed_custom_ids.len()).to_be(2);
+    expect!(progress.completed_custom_ids.contains("lyon")).to_be(true);
+    expect!(progress.valid_length).to_be(output.len());
+    expect!(progress.invalid_line_numbers.is_empty()).to_be(true);
+
+    // A run interrupted while writing its last line.
+    let truncated_output = format!("{}{}", output, r#"{"custom_id": "nice", "respo"#);
+    let progress = get_batch_output_progress(&truncated_output);
+
+    expect!(progress.completed_custom_ids.contains("nice")).to_be(false);
+    expect!(progress.valid_length).to_be(output.len());
+
+    // An invalid line doesn't drop the results following it.
+    let corrupted_output = r#"{"custom_id": "paris", "response": {"status_code": 200, "body": {}}, "error": null}
+not a batch output line
+{"custom_id": "lyon", "response": null, "error": {"message": "Timeout"}}
+"#;
+    let progress = get_batch_output_progress(corrupted_output);
+
+    expect!(progress.completed_custom_ids.len()).to_be(2);
+    expect!(progress.completed_custom_ids.contains("lyon")).to_be(true);
+    expect!(progress.valid_length).to_be(corrupted_output.len());
+    expect!(progress.invalid_line_numbers).to_be(vec![2]);
+}
+
+#[tokio::test]
+async fn test_client_run_batch_file_async() {
+    let (api_url, api_requests) = local_api::start_local_api(vec![
+        (429, json!({ "message": "Requests rate limit exceeded" })),
+        (
+            200,
+            json!({
+                "id": "cmpl-0",
+                "object": "chat.completion",
+                "created": 1736121600,
+                "model": "mistral-small-2503",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "A city." },
+                    "finish_reason": "stop"
+                }],
+                "usage": { "prompt_tokens": 1, "completion_tokens": 2, "total_tokens": 3 }
+            }),
+        ),
+    ]);
+    let client = Client::new(Some("api_key".to_string()), Some(api_url), None, None).unwrap();
+
+    let directory = std::env::temp_dir().join("mistralai_client_batch_runner_test");
+    std::fs::create_dir_all(&directory).unwrap();
+    let input_path = directory.join("batch.jsonl");
+    let output_path = directory.join("batch_output.jsonl");
+    let _ = std::fs::remove_file(&output_path);
+    std::fs::write(
+        &input_path,
+        r#"{"custom_id": "paris", "body": {"messages": [{"role": "user", "content": "Describe Paris."}]}}
+{"custom_id": "lyon", "body": {"messages": "Describe Lyon."}}
+"#,
+    )
+    .unwrap();
+    let options = BatchRunnerParams {
+        concurrency: 1,
+        model: Some("mistral-small-latest".to_string()),
+        rate_limit_initial_interval: Duration::from_millis(10),
+        ..Default::default()
+    };
+
+    let report = client
+        .run_batch_file_async(
+            &input_path,
+            &output_path,
+            BatchEndpoint::ChatCompletions,
+            Some(options.clone()),
+        )
+        .await
+        .unwrap();
+
+    expect!(report).to_be(BatchRunReport {
+        skipped_requests: 0,
+        succeeded_requests: 1,
+        failed_requests: 1,
+    });
+    // The rate-limited request is retried, while the invalid one is never sent.
+    expect!(api_requests.lock().unwrap().clone()).to_be(vec![
+        "POST /chat/completions (Bearer api_key)".to_string(),
+        "POST /chat/completions (Bearer api_key)".to_string(),
+    ]);
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    let output_lines: Vec<BatchOutputLine> = output
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let response = output_lines[0].response.as_ref().unwrap();
+    expect!(output_lines[0].custom_id.clone()).to_be("paris".to_string());
+    expect!(response.status_code).to_be(200);
+    expect!(response.body["choices"][0]["message"]["content"].clone()).to_be(json!("A city."));
+    expect!(output_lines[1].custom_id.clone()).to_be("lyon".to_string());
+    expect!(output_lines[1].response.is_none()).to_be(true);
+    expect!(output_lines[1].error.is_some()).to_be(true);
+
+    // A second run skips the requests already in the output file.
+    let report = client
+        .run_batch_file_async(
+            &input_path,
+            &output_path,
+            BatchEndpoint::ChatCompletions,
+            Some(options),
+        )
+        .await
+        .unwrap();
+
+    expect!(report.skipped_requests).to_be(2);
+    expect!(api_requests.lock().unwrap().len()).to_be(2);
+
+    expect!(client
+        .run_batch_file_async(&input_path, &output_path, BatchEndpoint::Moderations, None)
+        .await
+        .is_err())
+    .to_be(true);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+struct CountCallsFunction {
+    calls: Arc<AtomicUsize>,
+}
+#[async_trait::async_trait]
+impl Function for CountCallsFunction {
+    async fn execute(&self, _arguments: String) -> Box<dyn Any + Send> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+
+        Box::new(())
+    }
+}
+
+#[tokio::test]
+async fn test_client_run_batch_file_async_with_unknown_fields_and_functions() {
+    let (api_url, _, api_bodies) = local_api::start_local_api_with_bodies(vec![(
+        200,
+        json!({
+            "id": "cmpl-0",
+            "object": "chat.completion",
+            "created": 1736121600,
+            "model": "mistral-small-2503",
+            "choices": [{
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": "",
+                    "tool_calls": [{
+                        "id": "call_0",
+                        "function": { "name": "count_calls", "arguments": "{}" }
+                    }]
+                },
+                "finish_reason": "tool_calls"
+            }],
+            "usage": { "prompt_tokens": 1, "completion_tokens": 2, "total_tokens": 3 }
+        }),
+    )]);
+    let mut client = Client::new(Some("api_key".to_string()), Some(api_url), None, None).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    client.register_function(
+        "count_calls".to_string(),
+        Box::new(CountCallsFunction {
+            calls: calls.clone(),
+        }),
+    );
+
+    let directory = std::env::temp_dir().join("mistralai_client_batch_runner_functions_test");
+    std::fs::create_dir_all(&directory).unwrap();
+    let input_path = directory.join("batch.jsonl");
+    let output_path = directory.join("batch_output.jsonl");
+    let _ = std::fs::remove_file(&output_path);
+    std::fs::write(
+        &input_path,
+        r#"{"custom_id": "paris", "body": {"model": "mistral-small-latest", "messages": [{"role": "user", "content": "Count."}], "new_api_field": {"enabled": true}}}
+"#,
+    )
+    .unwrap();
+
+    let report = client
+        .run_batch_file_async(
+            &input_path,
+            &output_path,
+            BatchEndpoint::ChatCompletions,
+            None,
+        )
+        .await
+        .unwrap();
+
+    expect!(report.succeeded_requests).to_be(1);
+    // The fields unknown to the client are still sent.
+    let api_body = api_bodies.lock().unwrap()[0].clone();
+    expect!(api_body["new_api_field"].clone()).to_be(json!({ "enabled": true }));
+    expect!(api_body["model"].clone()).to_be(json!("mistral-small-latest"));
+    // The registered functions aren't called for batch responses.
+    expect!(calls.load(Ordering::SeqCst)).to_be(0);
+    expect!(client.get_last_function_call_result().is_none()).to_be(true);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,177 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    batch::{
+        get_batch_input_jsonl, BatchEndpoint, BatchJobListParams, BatchJobListRequest,
+        BatchJobResponse, BatchJobStatus, BatchJobWaitParams, BatchRequest, BatchRequestError,
+        BatchResults,
+    },
+    chat::{ChatMessage, ChatParams, ChatRequest, ChatResponse},
+    client::Client,
+    constants::EmbedModel,
+    embedding::{EmbeddingRequest, EmbeddingResponse},
+    utils,
+};
+use serde_json::json;
+use std::time::Duration;
+
+mod local_api;
+
+#[test]
+fn test_batch_input_jsonl() {
+    let options = ChatParams {
+        max_tokens: Some(100),
+        ..Default::default()
+    };
+    let requests = vec![BatchRequest::new(
+        "paris",
+        ChatRequest::new(
+            "mistral-small-latest".into(),
+            vec![ChatMessage::new_user_message("Describe Paris.")],
+            false,
+            Some(options),
+        ),
+    )];
+    let jsonl = String::from_utf8(get_batch_input_jsonl(&requests).unwrap()).unwrap();
+
+    expect!(jsonl.ends_with('\n')).to_be(true);
+    expect!(serde_json::from_str::<serde_json::Value>(&jsonl).unwrap()).to_be(json!({
+        "custom_id": "paris",
+        "body": {
+            "model": "mistral-small-latest",
+            "messages": [{ "role": "user", "content": "Describe Paris." }],
+            "max_tokens": 100,
+            "safe_prompt": false,
+            "stream": false
+        }
+    }));
+
+    let requests = vec![
+        BatchRequest::new(
+            "0",
+            EmbeddingRequest::new(EmbedModel::MistralEmbed, vec!["Paris".to_string()], None),
+        ),
+        BatchRequest::new(
+            "1",
+            EmbeddingRequest::new(EmbedModel::MistralEmbed, vec!["Lyon".to_string()], None),
+        ),
+    ];
+    let jsonl = String::from_utf8(get_batch_input_jsonl(&requests).unwrap()).unwrap();
+
+    expect!(jsonl.lines().count()).to_be(2);
+}
+
+#[test]
+fn test_batch_job_list_request_query_string() {
+    let request = BatchJobListRequest::new(Some(BatchJobListParams {
+        page_size: Some(10),
+        status: Some(vec![BatchJobStatus::Queued, BatchJobStatus::Running]),
+        ..Default::default()
+    }));
+
+    expect!(utils::get_query_string(&request))
+        .to_be("?page_size=10&status=QUEUED&status=RUNNING".to_string());
+}
+
+#[test]
+fn test_batch_job_response_deserialization() {
+    let job: BatchJobResponse = serde_json::from_value(json!({
+        "id": "b7b2a0f5-5e3b-4c5a-9f4e-1b8d6c7e2a10",
+        "object": "batch",
+        "input_files": ["input_file_id"],
+        "metadata": { "project": "support" },
+        "endpoint": "/v1/chat/completions",
+        "model": "mistral-small-latest",
+        "output_file": "output_file_id",
+        "error_file": null,
+        "errors": [],
+        "status": "TIMEOUT_EXCEEDED",
+        "created_at": 1716963433,
+        "total_requests": 10,
+        "completed_requests": 8,
+        "succeeded_requests": 8,
+        "failed_requests": 0,
+        "started_at": 1716963440,
+        "completed_at": 1717049833
+    }))
+    .unwrap();
+
+    expect!(job.endpoint).to_be(BatchEndpoint::ChatCompletions);
+    expect!(job.status.clone()).to_be(BatchJobStatus::TimeoutExceeded);
+    expect!(job.status.is_terminal()).to_be(true);
+    expect!(BatchJobStatus::CancellationRequested.is_terminal()).to_be(false);
+    expect!(job.output_file).to_be(Some("output_file_id".to_string()));
+}
+
+#[test]
+fn test_batch_results_from_jsonl() {
+    let output = r#"{"id": "batch-1", "custom_id": "paris", "response": {"status_code": 200, "body": {"id": "1", "object": "list", "model": "mistral-embed", "data": [{"object": "embedding", "embedding": [0.1, 0.2], "index": 0}], "usage": {"prompt_tokens": 1, "completion_tokens": 0, "total_tokens": 1}}}, "error": null}
+"#;
+    let errors = r#"{"id": "batch-2", "custom_id": "lyon", "response": {"status_code": 400, "body": {"message": "Invalid input"}}, "error": null}
+{"id": "batch-3", "custom_id": "nice", "response": null, "error": {"message": "Timeout"}}
+"#;
+
+    let results = BatchResults::<EmbeddingResponse>::from_jsonl(&[output, errors]).unwrap();
+
+    expect!(results.responses.len()).to_be(1);
+    assert_eq!(results.responses["paris"].data[0].embedding, vec![0.1, 0.2]);
+    expect!(results.errors["lyon"].clone()).to_be(BatchRequestError {
+        status_code: Some(400),
+        message: r#"{"message":"Invalid input"}"#.to_string(),
+    });
+    expect!(results.errors["nice"].clone()).to_be(BatchRequestError {
+        status_code: None,
+        message: r#"{"message":"Timeout"}"#.to_string(),
+    });
+
+    expect!(BatchResults::<ChatResponse>::from_jsonl(&["{}"]).is_err()).to_be(true);
+}
+
+fn get_batch_job_json(status: &str) -> serde_json::Value {
+    json!({
+        "id": "batch_job_id",
+        "object": "batch",
+        "input_files": ["input_file_id"],
+        "endpoint": "/v1/chat/completions",
+        "model": "mistral-small-latest",
+        "errors": [],
+        "status": status,
+        "created_at": 1716963433,
+        "total_requests": 10,
+        "completed_requests": 0,
+        "succeeded_requests": 0,
+        "failed_requests": 0
+    })
+}
+
+#[test]
+fn test_client_wait_batch_job_with_retryable_errors() {
+    let (url, requests) = local_api::start_local_api(vec![
+        (200, get_batch_job_json("RUNNING")),
+        (503, json!({ "message": "Service unavailable." })),
+        (200, get_batch_job_json("SUCCESS")),
+    ]);
+    let client = Client::new(Some("api_key".to_string()), Some(url), None, None).unwrap();
+    let options = BatchJobWaitParams {
+        initial_interval: Duration::from_millis(1),
+        max_interval: Duration::from_millis(10),
+        multiplier: 2.0,
+    };
+
+    let job = client
+        .wait_batch_job("batch_job_id", Some(options))
+        .unwrap();
+
+    expect!(job.status).to_be(BatchJobStatus::Success);
+    expect!(requests.lock().unwrap().len()).to_be(3);
+
+    let (url, requests) =
+        local_api::start_local_api(vec![(404, json!({ "message": "Not found." }))]);
+    let client = Client::new(Some("api_key".to_string()), Some(url), None, None).unwrap();
+
+    expect!(client
+        .wait_batch_job("batch_job_id", None)
+        .unwrap_err()
+        .status_code())
+    .to_be(Some(404));
+    expect!(requests.lock().unwrap().len()).to_be(1);
+}
@@ -0,0 +1,131 @@
+use jrest::expect;
+use mistralai_client::{
+    impl_chat_classification,
+    v1::{
+        chat::ChatResponse,
+        chat_classification::{
+            get_classification_tool, parse_classification_response, ChatClassificationParams,
+        },
+        client::Client,
+        constants::Model,
+        error::ChatParseError,
+        tool::Function,
+    },
+};
+use serde_json::json;
+use std::{
+    any::Any,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+mod local_api;
+
+#[derive(Debug, Eq, PartialEq)]
+enum Sentiment {
+    Positive,
+    Negative,
+}
+impl_chat_classification!(Sentiment {
+    Positive => ("positive", "The text expresses a positive opinion."),
+    Negative => ("negative", "The text expresses a negative opinion."),
+});
+
+fn get_chat_response(arguments: &str) -> ChatResponse {
+    serde_json::from_value(json!({
+        "id": "1",
+        "object": "chat.completion",
+        "created": 1718000000,
+        "model": "mistral-small-latest",
+        "choices": [{
+            "index": 0,
+            "message": {
+                "role": "assistant",
+                "content": "",
+                "tool_calls": [{
+                    "id": "D681PevKs",
+                    "function": { "name": "classify", "arguments": arguments }
+                }]
+            },
+            "finish_reason": "tool_calls"
+        }],
+        "usage": { "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_get_classification_tool() {
+    let params = ChatClassificationParams {
+        with_confidence: true,
+        ..Default::default()
+    };
+    let tool_json = serde_json::to_value(get_classification_tool::<Sentiment>(&params)).unwrap();
+    let parameters = tool_json["function"]["parameters"].clone();
+
+    expect!(tool_json["function"]["name"].clone()).to_be(json!("classify"));
+    expect!(parameters["properties"]["label"]["enum"].clone())
+        .to_be(json!(["positive", "negative"]));
+    expect!(parameters["properties"].get("rationale").is_none()).to_be(true);
+    expect!(parameters["required"].clone()).to_be(json!(["label", "confidence"]));
+}
+
+#[test]
+fn test_parse_classification_response() {
+    let response =
+        get_chat_response(r#"{"label": "negative", "confidence": 0.9, "rationale": "Angry."}"#);
+    let result = parse_classification_response::<Sentiment>(response).unwrap();
+
+    expect!(result.label).to_be(Sentiment::Negative);
+    expect!(result.confidence.unwrap() > 0.8).to_be(true);
+    expect!(result.rationale).to_be(Some("Angry.".to_string()));
+
+    let response = get_chat_response(r#"{"label": "neutral"}"#);
+    match parse_classification_response::<Sentiment>(response) {
+        Err(ChatParseError::InvalidContent { raw_content, .. }) => {
+            expect!(raw_content).to_be(r#"{"label": "neutral"}"#.to_string())
+        }
+        other => panic!(
+            "Expected `ChatParseError::InvalidContent` but got {:?}.",
+            other
+        ),
+    }
+}
+
+struct CountCallsFunction {
+    calls: Arc<AtomicUsize>,
+}
+#[async_trait::async_trait]
+impl Function for CountCallsFunction {
+    async fn execute(&self, _arguments: String) -> Box<dyn Any + Send> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+
+        Box::new(())
+    }
+}
+
+#[tokio::test]
+async fn test_client_chat_classify_async_with_registered_function() {
+    let response = serde_json::to_value(get_chat_response(r#"{"label": "negative"}"#)).unwrap();
+    let (url, _) = local_api::start_local_api(vec![(200, response)]);
+    let mut client = Client::new(Some("api_key".to_string()), Some(url), None, None).unwrap();
+    let calls = Arc::new(AtomicUsize::new(0));
+    client.register_function(
+        "classify".to_string(),
+        Box::new(CountCallsFunction {
+            calls: calls.clone(),
+        }),
+    );
+
+    let result = client
+        .chat_classify_async::<Sentiment>(Model::MistralSmallLatest, "It's broken.", None)
+        .await
+        .unwrap();
+
+    expect!(result.label).to_be(Sentiment::Negative);
+    // A registered function named like the classification function isn't called.
+    expect!(calls.load(Ordering::SeqCst)).to_be(0);
+    expect!(client.get_last_function_call_result().is_none()).to_be(true);
+}
@@ -0,0 +1,24 @@
+use jrest::expect;
+use mistralai_client::v1::chat_stream::get_chunk_from_stream_message_line;
+
+#[test]
+fn test_get_chunk_from_stream_message_line_with_thinking_delta() {
+    let line = r#"data: {"id":"1","object":"chat.completion.chunk","created":1718000000,"model":"magistral-small-latest","choices":[{"index":0,"delta":{"content":[{"type":"thinking","thinking":[{"type":"text","text":"Hmm"}]}]},"finish_reason":null}]}"#;
+
+    let chunks = get_chunk_from_stream_message_line(line).unwrap().unwrap();
+    expect!(chunks[0].choices[0].delta.content.thinking()).to_be("Hmm".to_string());
+    expect!(chunks[0].choices[0].delta.content.text()).to_be("".to_string());
+}
+
+#[test]
+fn test_get_chunk_from_stream_message_line_with_text_delta() {
+    let line = r#"data: {"id":"1","object":"chat.completion.chunk","created":1718000000,"model":"open-mistral-7b","choices":[{"index":0,"delta":{"role":"assistant","content":"Tower"},"finish_reason":null}]}"#;
+
+    let chunks = get_chunk_from_stream_message_line(line).unwrap().unwrap();
+    expect!(chunks[0].choices[0].delta.content.to_string()).to_be("Tower".to_string());
+
+    expect!(get_chunk_from_stream_message_line("data: [DONE]")
+        .unwrap()
+        .is_none())
+    .to_be(true);
+}
@@ -0,0 +1,382 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::{
+        ChatMessage, ChatMessageContent, ChatMessageContentChunk, ChatMessageRole, ChatParams,
+        ChatPrediction, ChatRequest, ChatResponse, ChatResponseChoiceFinishReason, ResponseFormat,
+        MAX_IMAGE_SIZE_BYTES,
+    },
+    constants::Model,
+    error::{ChatParseError, ImageError},
+    tool::ToolType,
+};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize, Eq, JsonSchema, PartialEq)]
+struct City {
+    name: String,
+    population: u32,
+}
+
+fn get_chat_response(content: &str) -> ChatResponse {
+    serde_json::from_value(json!({
+        "id": "1",
+        "object": "chat.completion",
+        "created": 1718000000,
+        "model": "mistral-small-latest",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": content },
+            "finish_reason": "stop"
+        }],
+        "usage": { "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_chat_message_with_tool_calls_round_trip() {
+    let assistant_message_json = json!({
+        "role": "assistant",
+        "content": "",
+        "tool_calls": [{
+            "id": "D681PevKs",
+            "type": "function",
+            "function": {
+                "name": "get_city_temperature",
+                "arguments": "{\"city\": \"Paris\"}"
+            },
+            "index": 0
+        }]
+    });
+
+    let assistant_message =
+        serde_json::from_value::<ChatMessage>(assistant_message_json.clone()).unwrap();
+    let tool_call = assistant_message.tool_calls.clone().unwrap()[0].clone();
+    expect!(tool_call.id.clone()).to_be(Some("D681PevKs".to_string()));
+    expect!(tool_call.r#type.clone()).to_be(Some(ToolType::Function));
+    expect!(tool_call.index).to_be(Some(0));
+    expect!(serde_json::to_value(&assistant_message).unwrap()).to_be(assistant_message_json);
+
+    let tool_message = ChatMessage::new_tool_message(
+        "20°C",
+        &tool_call.id.unwrap(),
+        Some(&tool_call.function.name),
+    );
+    expect!(serde_json::to_value(&tool_message).unwrap()).to_be(json!({
+        "role": "tool",
+        "content": "20°C",
+        "tool_call_id": "D681PevKs",
+        "name": "get_city_temperature"
+    }));
+}
+
+#[test]
+fn test_chat_message_constructors() {
+    expect!(ChatMessage::new_system_message("Be concise.").role).to_be(ChatMessageRole::System);
+    expect!(ChatMessage::new_user_message("Hello!").role).to_be(ChatMessageRole::User);
+    expect!(serde_json::to_value(ChatMessage::new_assistant_prefix_message("```json")).unwrap())
+        .to_be(json!({
+            "role": "assistant",
+            "content": "```json",
+            "prefix": true
+        }));
+}
+
+#[test]
+fn test_chat_message_with_image_chunks() {
+    let message = ChatMessage::new_user_message(vec![
+        ChatMessageContentChunk::new_text("What's in this image?"),
+        ChatMessageContentChunk::new_image_url("https://example.org/cat.jpg"),
+    ]);
+
+    expect!(serde_json::to_value(&message).unwrap()).to_be(json!({
+        "role": "user",
+        "content": [
+            { "type": "text", "text": "What's in this image?" },
+            { "type": "image_url", "image_url": "https://example.org/cat.jpg" }
+        ]
+    }));
+    expect!(message.content.text()).to_be("What's in this image?".to_string());
+}
+
+#[test]
+fn test_chat_message_content_deserialization() {
+    let text_message = serde_json::from_value::<ChatMessage>(json!({
+        "role": "assistant",
+        "content": "A cat."
+    }))
+    .unwrap();
+    expect!(text_message.content).to_be(ChatMessageContent::from("A cat."));
+
+    let chunks_message = serde_json::from_value::<ChatMessage>(json!({
+        "role": "user",
+        "content": [
+            { "type": "image_url", "image_url": { "url": "https://example.org/cat.jpg" } }
+        ]
+    }))
+    .unwrap();
+    expect!(chunks_message.content).to_be(ChatMessageContent::Chunks(vec![
+        ChatMessageContentChunk::new_image_url("https://example.org/cat.jpg"),
+    ]));
+
+    let null_content_message = serde_json::from_value::<ChatMessage>(json!({
+        "role": "assistant",
+        "content": null,
+    }))
+    .unwrap();
+    expect!(null_content_message.content).to_be(ChatMessageContent::from(""));
+}
+
+#[test]
+fn test_chat_message_content_chunk_new_image_from_file() {
+    let path = std::env::temp_dir().join("mistralai_client_test_image.bin");
+    std::fs::write(&path, b"\x89PNG\r\n\x1a\nfake").unwrap();
+
+    let chunk = ChatMessageContentChunk::new_image_from_file(&path).unwrap();
+    expect!(chunk).to_be(ChatMessageContentChunk::new_image_url(
+        "data:image/png;base64,iVBORw0KGgpmYWtl",
+    ));
+
+    std::fs::write(&path, b"not an image").unwrap();
+    match ChatMessageContentChunk::new_image_from_file(&path) {
+        Ok(_) => panic!("Expected `ImageError::UnsupportedFormat` but got Ok."),
+        Err(error) => expect!(matches!(error, ImageError::UnsupportedFormat(_))).to_be(true),
+    }
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_chat_message_content_chunk_new_image_from_bytes_too_large() {
+    let bytes = vec![0; MAX_IMAGE_SIZE_BYTES + 1];
+
+    match ChatMessageContentChunk::new_image_from_bytes(&bytes, "image/png") {
+        Ok(_) => panic!("Expected `ImageError::TooLarge` but got Ok."),
+        Err(error) => assert_eq!(
+            error,
+            ImageError::TooLarge {
+                size: MAX_IMAGE_SIZE_BYTES + 1,
+                max_size: MAX_IMAGE_SIZE_BYTES
+            }
+        ),
+    }
+}
+
+#[test]
+fn test_chat_message_with_document_chunks() {
+    let message = ChatMessage::new_user_message(vec![
+        ChatMessageContentChunk::new_text("Summarize these documents."),
+        ChatMessageContentChunk::new_document_url(
+            "https://arxiv.org/pdf/2310.06825",
+            Some("mistral-7b.pdf"),
+        ),
+        ChatMessageContentChunk::new_file("a1b2c3d4-0000-0000-0000-000000000000"),
+    ]);
+
+    let message_json = json!({
+        "role": "user",
+        "content": [
+            { "type": "text", "text": "Summarize these documents." },
+            {
+                "type": "document_url",
+                "document_url": "https://arxiv.org/pdf/2310.06825",
+                "document_name": "mistral-7b.pdf"
+            },
+            { "type": "file", "file_id": "a1b2c3d4-0000-0000-0000-000000000000" }
+        ]
+    });
+    expect!(serde_json::to_value(&message).unwrap()).to_be(message_json.clone());
+    expect!(
+        serde_json::from_value::<ChatMessage>(message_json)
+            .unwrap()
+            .content
+    )
+    .to_be(message.content);
+}
+
+#[test]
+fn test_chat_message_with_thinking_chunks() {
+    let message = serde_json::from_value::<ChatMessage>(json!({
+        "role": "assistant",
+        "content": [
+            {
+                "type": "thinking",
+                "thinking": [{ "type": "text", "text": "Paris has a famous tower." }]
+            },
+            { "type": "text", "text": "Tower" }
+        ]
+    }))
+    .unwrap();
+
+    expect!(message.content.thinking()).to_be("Paris has a famous tower.".to_string());
+    expect!(message.content.text()).to_be("Tower".to_string());
+    expect!(message.without_thinking().content).to_be(ChatMessageContent::Chunks(vec![
+        ChatMessageContentChunk::new_text("Tower"),
+    ]));
+}
+
+#[test]
+fn test_chat_params_json_default() {
+    let response_format = ChatParams::json_default().response_format.unwrap();
+
+    expect!(response_format.type_).to_be("json_object".to_string());
+}
+
+#[test]
+fn test_chat_request_serialization() {
+    let messages = vec![ChatMessage::new_user_message("Hello!")];
+
+    let request = ChatRequest::new(Model::MistralSmallLatest, messages.clone(), false, None);
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "messages": [{ "role": "user", "content": "Hello!" }],
+        "model": "mistral-small-latest",
+        "safe_prompt": false,
+        "stream": false
+    }));
+
+    let options = ChatParams {
+        frequency_penalty: Some(0.5),
+        min_tokens: Some(8),
+        n: Some(2),
+        prediction: Some(ChatPrediction::new("fn main() {}")),
+        presence_penalty: Some(0.25),
+        stop: Some(vec!["\n\n".to_string()]),
+        temperature: Some(0.0),
+        top_p: Some(0.5),
+        ..Default::default()
+    };
+    let request = ChatRequest::new(Model::MistralSmallLatest, messages, false, Some(options));
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "messages": [{ "role": "user", "content": "Hello!" }],
+        "model": "mistral-small-latest",
+        "frequency_penalty": 0.5,
+        "min_tokens": 8,
+        "n": 2,
+        "prediction": { "type": "content", "content": "fn main() {}" },
+        "presence_penalty": 0.25,
+        "safe_prompt": false,
+        "stop": ["\n\n"],
+        "stream": false,
+        "temperature": 0.0,
+        "top_p": 0.5
+    }));
+}
+
+#[test]
+fn test_response_format_json_schema_for() {
+    let response_format = ResponseFormat::json_schema_for::<City>(true);
+    let response_format_json = serde_json::to_value(&response_format).unwrap();
+
+    expect!(response_format_json["type"].clone()).to_be(json!("json_schema"));
+    expect!(response_format_json["json_schema"]["name"].clone()).to_be(json!("City"));
+    expect!(response_format_json["json_schema"]["strict"].clone()).to_be(json!(true));
+    expect!(response_format_json["json_schema"]["schema"]["required"].clone())
+        .to_be(json!(["name", "population"]));
+    expect!(response_format_json["json_schema"]["schema"]
+        .get("$schema")
+        .is_none())
+    .to_be(true);
+}
+
+#[test]
+fn test_chat_response_parse_content() {
+    let response = get_chat_response(r#"{"name": "Paris", "population": 2102650}"#);
+    expect!(response.parse_content::<City>().unwrap()).to_be(City {
+        name: "Paris".to_string(),
+        population: 2102650,
+    });
+
+    let response = get_chat_response(r#"{"name": "Paris""#);
+    match response.parse_content::<City>() {
+        Err(ChatParseError::InvalidContent { raw_content, .. }) => {
+            expect!(raw_content).to_be(r#"{"name": "Paris""#.to_string())
+        }
+        other => panic!(
+            "Expected `ChatParseError::InvalidContent` but got {:?}.",
+            other
+        ),
+    }
+}
+
+#[test]
+fn test_chat_response_parse_contents() {
+    let response: ChatResponse = serde_json::from_value(json!({
+        "id": "1",
+        "object": "chat.completion",
+        "created": 1718000000,
+        "model": "mistral-small-latest",
+        "choices": [
+            {
+                "index": 0,
+                "message": { "role": "assistant", "content": r#"{"name": "Paris", "population": 2102650}"# },
+                "finish_reason": "stop"
+            },
+            {
+                "index": 1,
+                "message": { "role": "assistant", "content": r#"{"name": "Lyon", "population": 522250}"# },
+                "finish_reason": "stop"
+            }
+        ],
+        "usage": { "prompt_tokens": 10, "completion_tokens": 10, "total_tokens": 20 }
+    }))
+    .unwrap();
+
+    expect!(response.parse_contents::<City>().unwrap()).to_be(vec![
+        City {
+            name: "Paris".to_string(),
+            population: 2102650,
+        },
+        City {
+            name: "Lyon".to_string(),
+            population: 522250,
+        },
+    ]);
+    expect!(response.choices[1].parse_content::<City>().unwrap().name).to_be("Lyon".to_string());
+}
+
+#[test]
+fn test_chat_response_with_unknown_values() {
+    let response_json = json!({
+        "id": "1",
+        "object": "chat.completion",
+        "created": 1718000000,
+        "model": "mistral-small-2506",
+        "choices": [
+            {
+                "index": 0,
+                "message": {
+                    "role": "assistant",
+                    "content": [
+                        { "type": "text", "text": "Paris" },
+                        { "type": "reference", "reference_ids": [1] }
+                    ]
+                },
+                "finish_reason": "length",
+                "logprobs": null
+            },
+            {
+                "index": 1,
+                "message": { "role": "narrator", "content": "Lyon" },
+                "finish_reason": "content_filter"
+            }
+        ],
+        "usage": { "prompt_tokens": 10, "completion_tokens": 10, "total_tokens": 20 },
+        "system_fingerprint": "abc"
+    });
+    let response = serde_json::from_value::<ChatResponse>(response_json.clone()).unwrap();
+
+    expect!(response.model.clone()).to_be(Model::Unknown("mistral-small-2506".to_string()));
+    expect!(response.extra["system_fingerprint"].clone()).to_be(json!("abc"));
+    expect!(response.choices[0].finish_reason.clone())
+        .to_be(ChatResponseChoiceFinishReason::Length);
+    expect!(response.choices[0].extra["logprobs"].clone()).to_be(json!(null));
+    expect!(response.choices[0].message.content.text()).to_be("Paris".to_string());
+    expect!(response.choices[1].finish_reason.clone()).to_be(
+        ChatResponseChoiceFinishReason::Unknown("content_filter".to_string()),
+    );
+    expect!(response.choices[1].message.role.clone())
+        .to_be(ChatMessageRole::Unknown("narrator".to_string()));
+    expect!(serde_json::to_value(&response).unwrap()).to_be(response_json);
+}
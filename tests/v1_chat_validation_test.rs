@@ -0,0 +1,60 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat_validation::{ChatValidator, SerdeValidator},
+    common::ResponseUsage,
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_serde_validator() {
+    let validator = SerdeValidator::<Vec<String>>::new();
+
+    expect!(validator.validate(r#"["Paris", "Lyon"]"#).unwrap())
+        .to_be(vec!["Paris".to_string(), "Lyon".to_string()]);
+    expect!(validator.validate(r#"["Paris", "Lyon""#).is_err()).to_be(true);
+}
+
+#[test]
+fn test_closure_validator() {
+    let validator = |content: &str| match content.trim().parse::<u32>() {
+        Ok(number) if number <= 100 => Ok(number),
+        Ok(number) => Err(format!("{} is greater than 100.", number)),
+        Err(error) => Err(error.to_string()),
+    };
+
+    expect!(validator.validate("42").unwrap()).to_be(42);
+    expect!(validator.validate("142").unwrap_err()).to_be("142 is greater than 100.".to_string());
+}
+
+#[test]
+fn test_response_usage_add() {
+    let mut usage = ResponseUsage::default();
+    usage += ResponseUsage {
+        prompt_tokens: 10,
+        completion_tokens: 5,
+        total_tokens: 15,
+        ..Default::default()
+    };
+    usage += serde_json::from_value(json!({
+        "prompt_tokens": 20,
+        "completion_tokens": 7,
+        "total_tokens": 27,
+        "prompt_audio_seconds": 3
+    }))
+    .unwrap();
+    usage += serde_json::from_value(json!({
+        "prompt_tokens": u32::MAX,
+        "completion_tokens": 0,
+        "total_tokens": 0,
+        "prompt_audio_seconds": 2
+    }))
+    .unwrap();
+
+    expect!(usage).to_be(ResponseUsage {
+        prompt_tokens: u32::MAX,
+        completion_tokens: 12,
+        total_tokens: 42,
+        extra: HashMap::from([("prompt_audio_seconds".to_string(), json!(5))]),
+    });
+}
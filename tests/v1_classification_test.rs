@@ -0,0 +1,52 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::ChatMessage,
+    classification::{ClassificationChatRequest, ClassificationResponse},
+    constants::Model,
+};
+use serde_json::json;
+
+#[test]
+fn test_classification_chat_request_serialization() {
+    let request = ClassificationChatRequest::new(
+        Model::new("ft:ministral-3b-latest:xxx:triage"),
+        vec![vec![ChatMessage::new_user_message(
+            "The app crashes when I upload a file.",
+        )]],
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "ft:ministral-3b-latest:xxx:triage",
+        "input": [{
+            "messages": [{ "role": "user", "content": "The app crashes when I upload a file." }]
+        }]
+    }));
+}
+
+#[test]
+fn test_classification_response_deserialization() {
+    let response: ClassificationResponse = serde_json::from_value(json!({
+        "id": "mod-e5cc70bb28c444948073e77776eb30ef",
+        "model": "ft:ministral-3b-latest:xxx:triage",
+        "results": [
+            {
+                "priority": {
+                    "scores": { "low": 0.05, "medium": 0.15, "high": 0.8 }
+                },
+                "team": {
+                    "scores": { "backend": 0.7, "frontend": 0.6, "billing": 0.01 }
+                }
+            }
+        ]
+    }))
+    .unwrap();
+    let result = &response.results[0];
+
+    expect!(response.model).to_be(Model::new("ft:ministral-3b-latest:xxx:triage"));
+    assert_eq!(result["priority"].get_top_label(), Some(("high", 0.8)));
+    assert_eq!(
+        result["team"].get_labels_above(0.5),
+        vec![("backend", 0.7), ("frontend", 0.6)]
+    );
+    expect!(result["team"].get_labels_above(0.9).is_empty()).to_be(true);
+}
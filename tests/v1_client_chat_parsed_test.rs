@@ -0,0 +1,51 @@
+use jrest::expect;
+use mistralai_client::v1::{chat::ChatMessage, client::Client, constants::Model};
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+mod setup;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct Monument {
+    name: String,
+    city: String,
+}
+
+#[test]
+fn test_client_chat_parsed() {
+    setup::setup();
+
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let model = Model::MistralSmallLatest;
+    let messages = vec![ChatMessage::new_user_message(
+        "Which monument is nicknamed \"La Dame de Fer\"?",
+    )];
+
+    let monument = client
+        .chat_parsed::<Monument>(model, messages, None)
+        .unwrap();
+
+    expect!(monument.name.contains("Eiffel")).to_be(true);
+    expect!(monument.city).to_be("Paris".to_string());
+}
+
+#[tokio::test]
+async fn test_client_chat_parsed_async() {
+    setup::setup();
+
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let model = Model::MistralSmallLatest;
+    let messages = vec![ChatMessage::new_user_message(
+        "Which monument is nicknamed \"La Dame de Fer\"?",
+    )];
+
+    let monument = client
+        .chat_parsed_async::<Monument>(model, messages, None)
+        .await
+        .unwrap();
+
+    expect!(monument.name.contains("Eiffel")).to_be(true);
+    expect!(monument.city).to_be("Paris".to_string());
+}
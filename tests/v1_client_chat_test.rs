@@ -19,7 +19,7 @@ fn test_client_chat() {
         "Guess the next word: \"Eiffel ...\"?",
     )];
     let options = ChatParams {
-        temperature: 0.0,
+        temperature: Some(0.0),
         random_seed: Some(42),
         ..Default::default()
     };
@@ -31,12 +31,7 @@ fn test_client_chat() {
     expect!(response.choices.len()).to_be(1);
     expect!(response.choices[0].index).to_be(0);
     expect!(response.choices[0].message.role.clone()).to_be(ChatMessageRole::Assistant);
-    expect!(response.choices[0]
-        .message
-        .content
-        .clone()
-        .contains("Tower"))
-    .to_be(true);
+    expect!(response.choices[0].message.content.text().contains("Tower")).to_be(true);
     expect!(response.choices[0].finish_reason.clone()).to_be(ChatResponseChoiceFinishReason::Stop);
     expect!(response.usage.prompt_tokens).to_be_greater_than(0);
     expect!(response.usage.completion_tokens).to_be_greater_than(0);
@@ -64,7 +59,7 @@ fn test_client_chat_with_function_calling() {
         "What's the current temperature in Paris?",
     )];
     let options = ChatParams {
-        temperature: 0.0,
+        temperature: Some(0.0),
         random_seed: Some(42),
         tool_choice: Some(ToolChoice::Auto),
         tools: Some(tools),
@@ -78,7 +73,7 @@ fn test_client_chat_with_function_calling() {
     expect!(response.choices.len()).to_be(1);
     expect!(response.choices[0].index).to_be(0);
     expect!(response.choices[0].message.role.clone()).to_be(ChatMessageRole::Assistant);
-    expect!(response.choices[0].message.content.clone()).to_be("".to_string());
+    expect!(response.choices[0].message.content.text()).to_be("".to_string());
     expect!(response.choices[0].finish_reason.clone())
         .to_be(ChatResponseChoiceFinishReason::ToolCalls);
     expect!(response.usage.prompt_tokens).to_be_greater_than(0);
@@ -0,0 +1,90 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::{ChatMessage, ChatParams},
+    chat_validation::SerdeValidator,
+    client::Client,
+    constants::Model,
+    error::ChatValidationError,
+};
+use serde_json::json;
+
+mod local_api;
+mod setup;
+
+#[test]
+fn test_client_chat_validated() {
+    setup::setup();
+
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let model = Model::MistralSmallLatest;
+    let messages = vec![ChatMessage::new_user_message(
+        "List the 3 largest French cities as a JSON array of strings.",
+    )];
+    let options = ChatParams {
+        random_seed: Some(42),
+        ..ChatParams::json_default()
+    };
+
+    let result = client
+        .chat_validated(
+            model,
+            messages,
+            Some(options),
+            &SerdeValidator::<Vec<String>>::new(),
+            2,
+        )
+        .unwrap();
+
+    expect!(result.value.len()).to_be(3);
+    expect!(result.attempts.len()).to_be_greater_than(0);
+    expect!(result.usage.total_tokens).to_be_greater_than(0);
+}
+
+#[test]
+fn test_client_chat_validated_with_api_error() {
+    let (url, _) = local_api::start_local_api(vec![
+        (
+            200,
+            json!({
+                "id": "cmpl-0",
+                "object": "chat.completion",
+                "created": 1736121600,
+                "model": "mistral-small-2503",
+                "choices": [{
+                    "index": 0,
+                    "message": { "role": "assistant", "content": "Paris, Lyon" },
+                    "finish_reason": "stop"
+                }],
+                "usage": { "prompt_tokens": 10, "completion_tokens": 3, "total_tokens": 13 }
+            }),
+        ),
+        (503, json!({ "message": "Service unavailable." })),
+    ]);
+    let client = Client::new(Some("api_key".to_string()), Some(url), None, None).unwrap();
+    let messages = vec![ChatMessage::new_user_message("List 2 French cities.")];
+
+    let error = client
+        .chat_validated(
+            Model::MistralSmallLatest,
+            messages,
+            None,
+            &SerdeValidator::<Vec<String>>::new(),
+            2,
+        )
+        .unwrap_err();
+
+    match error {
+        ChatValidationError::Api {
+            error,
+            attempts,
+            usage,
+        } => {
+            expect!(error.status_code()).to_be(Some(503));
+            expect!(attempts.len()).to_be(1);
+            expect!(attempts[0].error.is_some()).to_be(true);
+            expect!(usage.total_tokens).to_be(13);
+        }
+        error => panic!("Expected `ChatValidationError::Api` but got {:?}.", error),
+    }
+}
@@ -0,0 +1,166 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::ChatMessage,
+    client::{Client, ClientEndpoint, ClientRoute},
+    constants::{EmbedModel, Model, API_URL_BASE, CODESTRAL_API_URL_BASE},
+    model_registry::ModelResolutionParams,
+};
+use serde_json::json;
+
+mod local_api;
+
+#[test]
+fn test_client_get_endpoint_with_codestral_endpoint() {
+    let mut client = Client::new(Some("api_key".to_string()), None, None, None).unwrap();
+    client.set_codestral_endpoint("codestral_api_key".to_string());
+
+    expect!(client.get_endpoint("/fim/completions", Some("codestral-2405")))
+        .to_be((CODESTRAL_API_URL_BASE, "codestral_api_key"));
+    expect!(client.get_endpoint("/chat/completions", Some("codestral-latest")))
+        .to_be((CODESTRAL_API_URL_BASE, "codestral_api_key"));
+    expect!(client.get_endpoint("/chat/completions", Some("open-mistral-7b")))
+        .to_be((API_URL_BASE, "api_key"));
+    expect!(client.get_endpoint("/models", None)).to_be((API_URL_BASE, "api_key"));
+}
+
+#[test]
+fn test_client_get_endpoint_with_overrides_precedence() {
+    let mut client = Client::new(Some("api_key".to_string()), None, None, None).unwrap();
+    client.set_endpoint_override(
+        ClientRoute::Path("/chat".to_string()),
+        ClientEndpoint {
+            url: "https://chat.example.org".to_string(),
+            api_key: "chat_api_key".to_string(),
+        },
+    );
+    client.set_endpoint_override(
+        ClientRoute::Model("my-model".to_string()),
+        ClientEndpoint {
+            url: "https://model.example.org".to_string(),
+            api_key: "model_api_key".to_string(),
+        },
+    );
+
+    expect!(client.get_endpoint("/chat/completions", Some("my-model")))
+        .to_be(("https://model.example.org", "model_api_key"));
+    expect!(client.get_endpoint("/chat/completions", Some("open-mistral-7b")))
+        .to_be(("https://chat.example.org", "chat_api_key"));
+
+    client.set_endpoint_override(
+        ClientRoute::Path("/chat".to_string()),
+        ClientEndpoint {
+            url: "https://new-chat.example.org".to_string(),
+            api_key: "new_chat_api_key".to_string(),
+        },
+    );
+
+    expect!(client.get_endpoint("/chat/completions", None))
+        .to_be(("https://new-chat.example.org", "new_chat_api_key"));
+}
+
+#[test]
+fn test_client_chat_with_codestral_endpoint_and_model_resolution() {
+    let (api_url, api_requests) = local_api::start_local_api(vec![(
+        200,
+        json!({
+            "object": "list",
+            "data": [{
+                "id": "codestral-2501",
+                "object": "model",
+                "created": 1736121600,
+                "owned_by": "mistralai",
+                "capabilities": { "completion_chat": true, "completion_fim": true },
+                "aliases": ["codestral-latest"]
+            }]
+        }),
+    )]);
+    let (codestral_url, codestral_requests) = local_api::start_local_api(vec![(
+        200,
+        json!({
+            "id": "cmpl-0",
+            "object": "chat.completion",
+            "created": 1736121600,
+            "model": "codestral-2501",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "fn main() {}" },
+                "finish_reason": "stop"
+            }],
+            "usage": { "prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2 }
+        }),
+    )]);
+
+    let mut client = Client::new(Some("api_key".to_string()), Some(api_url), None, None).unwrap();
+    client.set_codestral_endpoint("codestral_api_key".to_string());
+    // Redirects the Codestral traffic to the local API, like `set_codestral_endpoint()` does.
+    client.set_endpoint_override(
+        ClientRoute::Model("codestral-latest".to_string()),
+        ClientEndpoint {
+            url: codestral_url,
+            api_key: "codestral_api_key".to_string(),
+        },
+    );
+    client.set_model_resolution(Some(ModelResolutionParams::default()));
+
+    let messages = vec![ChatMessage::new_user_message("Write a Rust program.")];
+    let response = client.chat(Model::CodestralLatest, messages, None).unwrap();
+
+    expect!(response.model).to_be(Model::new("codestral-2501"));
+    expect!(api_requests.lock().unwrap().clone())
+        .to_be(vec!["GET /models (Bearer api_key)".to_string()]);
+    expect!(codestral_requests.lock().unwrap().clone()).to_be(vec![
+        "POST /chat/completions (Bearer codestral_api_key)".to_string(),
+    ]);
+}
+
+#[test]
+fn test_client_embeddings_with_model_override_and_model_resolution() {
+    let (api_url, api_requests) = local_api::start_local_api(vec![(
+        200,
+        json!({
+            "object": "list",
+            "data": [{
+                "id": "mistral-embed-2312",
+                "object": "model",
+                "created": 1702425600,
+                "owned_by": "mistralai",
+                "capabilities": { "completion_chat": false },
+                "aliases": ["mistral-embed-latest"]
+            }]
+        }),
+    )]);
+    let (embed_url, embed_requests) = local_api::start_local_api(vec![(
+        200,
+        json!({
+            "id": "embd-0",
+            "object": "list",
+            "model": "mistral-embed-2312",
+            "data": [{ "object": "embedding", "embedding": [0.1, 0.2], "index": 0 }],
+            "usage": { "prompt_tokens": 1, "completion_tokens": 0, "total_tokens": 1 }
+        }),
+    )]);
+
+    let mut client = Client::new(Some("api_key".to_string()), Some(api_url), None, None).unwrap();
+    client.set_endpoint_override(
+        ClientRoute::Model("mistral-embed-latest".to_string()),
+        ClientEndpoint {
+            url: embed_url,
+            api_key: "embed_api_key".to_string(),
+        },
+    );
+    client.set_model_resolution(Some(ModelResolutionParams::default()));
+
+    client
+        .embeddings(
+            EmbedModel::new("mistral-embed-latest"),
+            vec!["Hello".to_string()],
+            None,
+        )
+        .unwrap();
+
+    // The override set on the alias still applies once the alias is resolved.
+    expect!(api_requests.lock().unwrap().clone())
+        .to_be(vec!["GET /models (Bearer api_key)".to_string()]);
+    expect!(embed_requests.lock().unwrap().clone())
+        .to_be(vec!["POST /embeddings (Bearer embed_api_key)".to_string()]);
+}
@@ -0,0 +1,74 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    client::Client,
+    file::{FileListParams, FilePurpose},
+};
+use std::io::Write;
+
+#[test]
+fn test_client_files() {
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let content = r#"{"messages": [{"role": "user", "content": "Hi!"}, {"role": "assistant", "content": "Hello!"}]}"#;
+    let path = std::env::temp_dir().join("mistralai_client_test_client_files.jsonl");
+    let mut file = std::fs::File::create(&path).unwrap();
+    writeln!(file, "{}", content).unwrap();
+
+    let uploaded_file = client.upload_file(&path, FilePurpose::FineTune).unwrap();
+    expect!(uploaded_file.filename.clone())
+        .to_be("mistralai_client_test_client_files.jsonl".to_string());
+    expect!(uploaded_file.purpose.clone()).to_be(FilePurpose::FineTune);
+
+    let retrieved_file = client.get_file(&uploaded_file.id).unwrap();
+    expect!(retrieved_file.id).to_be(uploaded_file.id.clone());
+
+    let files = client
+        .list_files(Some(FileListParams {
+            purpose: Some(FilePurpose::FineTune),
+            ..Default::default()
+        }))
+        .unwrap();
+    expect!(files.total).to_be_greater_than(0);
+
+    let mut downloaded_content = Vec::new();
+    client
+        .download_file(&uploaded_file.id, &mut downloaded_content)
+        .unwrap();
+    expect!(String::from_utf8(downloaded_content).unwrap().trim()).to_be(content);
+
+    let deleted_file = client.delete_file(&uploaded_file.id).unwrap();
+    expect!(deleted_file.deleted).to_be(true);
+}
+
+#[tokio::test]
+async fn test_client_files_async() {
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let content =
+        r#"{"custom_id": "0", "body": {"messages": [{"role": "user", "content": "Hi!"}]}}"#;
+    let path = std::env::temp_dir().join("mistralai_client_test_client_files_async.jsonl");
+    let mut file = std::fs::File::create(&path).unwrap();
+    writeln!(file, "{}", content).unwrap();
+
+    let uploaded_file = client
+        .upload_file_async(&path, FilePurpose::Batch)
+        .await
+        .unwrap();
+    expect!(uploaded_file.purpose.clone()).to_be(FilePurpose::Batch);
+
+    let signed_url = client
+        .get_file_signed_url_async(&uploaded_file.id, Some(1))
+        .await
+        .unwrap();
+    expect!(signed_url.url.starts_with("https://")).to_be(true);
+
+    let mut downloaded_content = Vec::new();
+    client
+        .download_file_async(&uploaded_file.id, &mut downloaded_content)
+        .await
+        .unwrap();
+    expect!(String::from_utf8(downloaded_content).unwrap().trim()).to_be(content);
+
+    let deleted_file = client.delete_file_async(&uploaded_file.id).await.unwrap();
+    expect!(deleted_file.deleted).to_be(true);
+}
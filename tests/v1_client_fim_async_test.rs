@@ -0,0 +1,33 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::ChatResponseChoiceFinishReason, client::Client, constants::Model, fim::FimParams,
+};
+
+mod setup;
+
+#[tokio::test]
+async fn test_client_fim_async() {
+    setup::setup();
+
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let model = Model::CodestralLatest;
+    let prompt = "def fibonacci(n: int):".to_string();
+    let options = FimParams {
+        suffix: Some("\n\nprint(fibonacci(10))".to_string()),
+        temperature: Some(0.0),
+        random_seed: Some(42),
+        ..Default::default()
+    };
+
+    let response = client
+        .fim_async(model, prompt, Some(options))
+        .await
+        .unwrap();
+
+    expect!(response.model).to_be(Model::CodestralLatest);
+    expect!(response.choices.len()).to_be(1);
+    expect!(response.choices[0].message.content.text().len()).to_be_greater_than(0);
+    expect!(response.choices[0].finish_reason.clone()).to_be(ChatResponseChoiceFinishReason::Stop);
+    expect!(response.usage.total_tokens).to_be_greater_than(0);
+}
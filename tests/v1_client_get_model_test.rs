@@ -0,0 +1,26 @@
+use jrest::expect;
+use mistralai_client::v1::{client::Client, model_list::ModelType};
+
+#[test]
+fn test_client_get_model() {
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let response = client.get_model("mistral-small-latest").unwrap();
+
+    expect!(response.object).to_be("model".to_string());
+    expect!(response.r#type).to_be(Some(ModelType::Base));
+    expect!(response.capabilities.completion_chat).to_be(true);
+}
+
+#[tokio::test]
+async fn test_client_get_model_async() {
+    let client = Client::new(None, None, None, None).unwrap();
+
+    let response = client
+        .get_model_async("mistral-small-latest")
+        .await
+        .unwrap();
+
+    expect!(response.object).to_be("model".to_string());
+    expect!(response.capabilities.completion_chat).to_be(true);
+}
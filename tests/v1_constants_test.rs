@@ -2,8 +2,9 @@ use jrest::expect;
 use mistralai_client::v1::{
     chat::{ChatMessage, ChatParams},
     client::Client,
-    constants::Model,
+    constants::{EmbedModel, Model},
 };
+use serde_json::json;
 
 #[test]
 fn test_model_constant() {
@@ -22,7 +23,7 @@ fn test_model_constant() {
 
     let messages = vec![ChatMessage::new_user_message("A number between 0 and 100?")];
     let options = ChatParams {
-        temperature: 0.0,
+        temperature: Some(0.0),
         random_seed: Some(42),
         ..Default::default()
     };
@@ -36,6 +37,43 @@ fn test_model_constant() {
         expect!(response.object).to_be("chat.completion".to_string());
         expect!(response.choices.len()).to_be(1);
         expect!(response.choices[0].index).to_be(0);
-        expect!(response.choices[0].message.content.len()).to_be_greater_than(0);
+        expect!(response.choices[0].message.content.text().len()).to_be_greater_than(0);
     }
 }
+
+#[test]
+fn test_model_from_id() {
+    expect!(Model::from("codestral-latest")).to_be(Model::CodestralLatest);
+    expect!(Model::from(
+        "ft:open-mistral-7b:587a6b29:20240514:7e773925".to_string()
+    ))
+    .to_be(Model::Unknown(
+        "ft:open-mistral-7b:587a6b29:20240514:7e773925".to_string(),
+    ));
+    expect!(Model::MistralLargeLatest.to_string()).to_be("mistral-large-latest".to_string());
+    expect!(Model::new("mistral-large-2411").as_str()).to_be("mistral-large-2411");
+    expect!(EmbedModel::from("mistral-embed")).to_be(EmbedModel::MistralEmbed);
+}
+
+#[test]
+fn test_model_serialization() {
+    let models = vec![
+        Model::MistralSmallLatest,
+        Model::new("ft:open-mistral-7b:587a6b29:20240514:7e773925"),
+    ];
+    let models_json = serde_json::to_value(&models).unwrap();
+
+    expect!(models_json.clone()).to_be(json!([
+        "mistral-small-latest",
+        "ft:open-mistral-7b:587a6b29:20240514:7e773925"
+    ]));
+    expect!(serde_json::from_value::<Vec<Model>>(models_json).unwrap()).to_be(models);
+}
+
+#[test]
+fn test_model_equality_with_known_id() {
+    expect!(Model::Unknown("codestral-latest".to_string())).to_be(Model::CodestralLatest);
+    expect!(EmbedModel::Unknown("mistral-embed".to_string())).to_be(EmbedModel::MistralEmbed);
+    expect!(Model::Unknown("mistral-large-2411".to_string()) == Model::MistralLargeLatest)
+        .to_be(false);
+}
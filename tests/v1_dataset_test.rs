@@ -0,0 +1,163 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::ChatMessage,
+    dataset::{validate_jsonl, Dataset, DatasetSample, EstimatedTokenCounter},
+    error::{DatasetError, DatasetSampleError},
+    tool::{ToolCall, ToolCallFunction},
+};
+
+fn get_tool_call(id: &str, arguments: &str) -> ToolCall {
+    ToolCall {
+        id: Some(id.to_string()),
+        r#type: None,
+        function: ToolCallFunction {
+            name: "get_weather".to_string(),
+            arguments: arguments.to_string(),
+        },
+        index: None,
+    }
+}
+
+#[test]
+fn test_dataset_sample_validate() {
+    let sample = DatasetSample::new(vec![
+        ChatMessage::new_system_message("You are a weather assistant."),
+        ChatMessage::new_user_message("What's the weather in Paris?"),
+        ChatMessage::new_assistant_message(
+            "",
+            Some(vec![get_tool_call("D681PevKs", r#"{"city": "Paris"}"#)]),
+        ),
+        ChatMessage::new_tool_message("Sunny, 25°C.", "D681PevKs", None),
+        ChatMessage::new_assistant_message("It's sunny and 25°C in Paris.", None),
+    ]);
+    expect!(sample.validate()).to_be(Ok(()));
+
+    let sample = DatasetSample::new(vec![
+        ChatMessage::new_user_message("Hi!"),
+        ChatMessage::new_user_message("Hello?"),
+    ]);
+    expect!(sample.validate()).to_be(Err(DatasetSampleError::UnexpectedRole {
+        index: 1,
+        role: "user".to_string(),
+        expected: "`assistant`".to_string(),
+    }));
+
+    let sample = DatasetSample::new(vec![
+        ChatMessage::new_user_message("Hi!"),
+        ChatMessage::new_assistant_message(" ", None),
+    ]);
+    expect!(sample.validate()).to_be(Err(DatasetSampleError::EmptyMessage {
+        index: 1,
+        role: "assistant".to_string(),
+    }));
+
+    let sample = DatasetSample::new(vec![
+        ChatMessage::new_user_message("What's the weather in Paris?"),
+        ChatMessage::new_assistant_message(
+            "",
+            Some(vec![get_tool_call("D681PevKs", r#"["Paris"]"#)]),
+        ),
+    ]);
+    expect!(sample.validate()).to_be(Err(DatasetSampleError::InvalidToolCall {
+        index: 1,
+        message: "the `get_weather` call arguments aren't a JSON object".to_string(),
+    }));
+
+    let sample = DatasetSample::new(vec![
+        ChatMessage::new_user_message("What's the weather in Paris?"),
+        ChatMessage::new_assistant_message(
+            "",
+            Some(vec![get_tool_call("D681PevKs", r#"{"city": "Paris"}"#)]),
+        ),
+        ChatMessage::new_tool_message("Sunny, 25°C.", "cgQxiYmG8", None),
+    ]);
+    expect!(sample.validate()).to_be(Err(DatasetSampleError::UnmatchedToolResult {
+        index: 2,
+        tool_call_id: Some("cgQxiYmG8".to_string()),
+    }));
+
+    let sample = DatasetSample::new(vec![
+        ChatMessage::new_system_message("You are a weather assistant."),
+        ChatMessage::new_user_message("Hi!"),
+    ]);
+    expect!(sample.validate()).to_be(Err(DatasetSampleError::InvalidLastMessage {
+        role: "user".to_string(),
+    }));
+}
+
+#[test]
+fn test_validate_jsonl() {
+    let jsonl = r#"{"messages": [{"role": "user", "content": "Hi!"}, {"role": "assistant", "content": "Hello there!"}]}
+
+{"messages": [{"role": "user", "content": "Hi!"}, {"role": "assistant", "content": "Hello!"}
+{"messages": [{"role": "assistant", "content": "Hello!"}]}
+{"messages": [{"role": "user", "content": "Hi!"}, {"role": "assistant", "content": "Hey!"}]}"#;
+
+    let report = validate_jsonl(jsonl.as_bytes(), &EstimatedTokenCounter).unwrap();
+
+    expect!(report.is_valid()).to_be(false);
+    expect!(report.statistics.sample_count).to_be(2);
+    expect!(report.statistics.message_count).to_be(4);
+    // "Hi!" (1) + "Hello there!" (3), then "Hi!" (1) + "Hey!" (1)
+    expect!(report.statistics.token_count).to_be(6);
+    expect!(report.statistics.assistant_token_count).to_be(4);
+    expect!(report.statistics.min_sample_token_count).to_be(2);
+    expect!(report.statistics.max_sample_token_count).to_be(4);
+    assert_eq!(report.statistics.mean_sample_token_count, 3.0);
+
+    expect!(report.errors.len()).to_be(2);
+    expect!(matches!(
+        report.errors[0],
+        DatasetError::InvalidJson { line: 3, .. }
+    ))
+    .to_be(true);
+    expect!(report.errors[1].to_string()).to_be(
+        "Line 4: Unexpected `assistant` message at `messages[0]`, expected a `system` or `user` message."
+            .to_string(),
+    );
+}
+
+#[test]
+fn test_dataset_jsonl_round_trip_and_split() {
+    let mut dataset = Dataset::new();
+    for index in 0..10 {
+        dataset
+            .push_conversation(vec![
+                ChatMessage::new_user_message(format!("Question {}", index)),
+                ChatMessage::new_assistant_message(format!("Answer {}", index), None),
+            ])
+            .unwrap();
+    }
+    expect!(dataset
+        .push_conversation(vec![ChatMessage::new_user_message("Question")])
+        .is_err())
+    .to_be(true);
+
+    let mut jsonl = Vec::new();
+    dataset.write_jsonl(&mut jsonl).unwrap();
+    let jsonl = String::from_utf8(jsonl).unwrap();
+    expect!(jsonl.lines().next().unwrap().to_string()).to_be(
+        r#"{"messages":[{"role":"user","content":"Question 0"},{"role":"assistant","content":"Answer 0"}]}"#
+            .to_string(),
+    );
+
+    let dataset = Dataset::from_jsonl(jsonl.as_bytes()).unwrap();
+    expect!(dataset.len()).to_be(10);
+
+    let get_first_messages = |dataset: &Dataset| -> Vec<String> {
+        dataset
+            .samples()
+            .iter()
+            .map(|sample| sample.messages[0].content.text())
+            .collect()
+    };
+    let (training_dataset, validation_dataset) = dataset.clone().split(0.2, 42);
+    let (other_training_dataset, other_validation_dataset) = dataset.split(0.2, 42);
+
+    expect!(training_dataset.len()).to_be(8);
+    expect!(validation_dataset.len()).to_be(2);
+    expect!(get_first_messages(&training_dataset))
+        .to_be(get_first_messages(&other_training_dataset));
+    expect!(get_first_messages(&validation_dataset))
+        .to_be(get_first_messages(&other_validation_dataset));
+}
@@ -0,0 +1,50 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    file::{
+        FileListParams, FileListRequest, FilePurpose, FileResponse, FileSampleType, FileSource,
+    },
+    utils,
+};
+use serde_json::json;
+
+#[test]
+fn test_file_list_request_query_string() {
+    expect!(utils::get_query_string(&FileListRequest::new(None))).to_be("".to_string());
+
+    let request = FileListRequest::new(Some(FileListParams {
+        page: Some(2),
+        page_size: Some(50),
+        purpose: Some(FilePurpose::FineTune),
+        sample_type: Some(vec![FileSampleType::Instruct, FileSampleType::Pretrain]),
+        search: Some("support & sales".to_string()),
+        ..Default::default()
+    }));
+
+    expect!(utils::get_query_string(&request)).to_be(
+        "?page=2&page_size=50&purpose=fine-tune&sample_type=instruct&sample_type=pretrain&search=support+%26+sales"
+            .to_string(),
+    );
+}
+
+#[test]
+fn test_file_response_deserialization() {
+    let file: FileResponse = serde_json::from_value(json!({
+        "id": "497f6eca-6276-4993-bfeb-53cbbbba6f09",
+        "object": "file",
+        "bytes": 13000,
+        "created_at": 1716963433,
+        "filename": "training_file.jsonl",
+        "purpose": "fine-tune",
+        "sample_type": "instruct",
+        "num_lines": 42,
+        "source": "upload",
+        "mimetype": "application/jsonl"
+    }))
+    .unwrap();
+
+    expect!(file.purpose).to_be(FilePurpose::FineTune);
+    expect!(file.sample_type).to_be(Some(FileSampleType::Instruct));
+    expect!(file.source).to_be(Some(FileSource::Upload));
+    expect!(file.num_lines).to_be(Some(42));
+    expect!(file.extra["mimetype"].clone()).to_be(json!("application/jsonl"));
+}
@@ -0,0 +1,256 @@
+use futures::StreamExt;
+use jrest::expect;
+use mistralai_client::v1::{
+    client::Client,
+    constants::Model,
+    error::{ApiError, ApiErrorKind},
+    fine_tuning::{
+        FineTuningClassifierLossFunction, FineTuningClassifierTarget, FineTuningHyperparameters,
+        FineTuningIntegration, FineTuningJobListParams, FineTuningJobListRequest,
+        FineTuningJobParams, FineTuningJobRequest, FineTuningJobResponse, FineTuningJobStatus,
+        FineTuningJobType, FineTuningJobWatchParams, FineTuningTrainingFile,
+    },
+    utils,
+};
+use serde_json::json;
+use std::time::Duration;
+
+mod local_api;
+
+#[test]
+fn test_fine_tuning_job_request_serialization() {
+    let request = FineTuningJobRequest::new(
+        Model::OpenMistral7b,
+        vec![FineTuningTrainingFile::new("training_file_id")],
+        None,
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "open-mistral-7b",
+        "training_files": [{ "file_id": "training_file_id" }],
+        "hyperparameters": {}
+    }));
+
+    let request = FineTuningJobRequest::new(
+        Model::OpenMistral7b,
+        vec![FineTuningTrainingFile {
+            file_id: "training_file_id".to_string(),
+            weight: Some(0.5),
+        }],
+        Some(FineTuningJobParams {
+            auto_start: Some(false),
+            hyperparameters: Some(FineTuningHyperparameters {
+                training_steps: Some(10),
+                learning_rate: Some(0.0001),
+                ..Default::default()
+            }),
+            integrations: Some(vec![FineTuningIntegration::new_wandb(
+                "my-project",
+                "wandb_api_key",
+            )]),
+            suffix: Some("support-bot".to_string()),
+            validation_files: Some(vec!["validation_file_id".to_string()]),
+            ..Default::default()
+        }),
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "open-mistral-7b",
+        "training_files": [{ "file_id": "training_file_id", "weight": 0.5 }],
+        "auto_start": false,
+        "hyperparameters": { "training_steps": 10, "learning_rate": 0.0001 },
+        "integrations": [{ "type": "wandb", "project": "my-project", "api_key": "wandb_api_key" }],
+        "suffix": "support-bot",
+        "validation_files": ["validation_file_id"]
+    }));
+}
+
+#[test]
+fn test_classifier_fine_tuning_job_request_serialization() {
+    let request = FineTuningJobRequest::new(
+        Model::new("ministral-3b-latest"),
+        vec![FineTuningTrainingFile::new("training_file_id")],
+        Some(FineTuningJobParams {
+            classifier_targets: Some(vec![FineTuningClassifierTarget {
+                loss_function: Some(FineTuningClassifierLossFunction::SingleClass),
+                ..FineTuningClassifierTarget::new(
+                    "priority",
+                    vec!["low".to_string(), "high".to_string()],
+                )
+            }]),
+            job_type: Some(FineTuningJobType::Classifier),
+            ..Default::default()
+        }),
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "ministral-3b-latest",
+        "training_files": [{ "file_id": "training_file_id" }],
+        "classifier_targets": [{
+            "name": "priority",
+            "labels": ["low", "high"],
+            "loss_function": "single_class"
+        }],
+        "hyperparameters": {},
+        "job_type": "classifier"
+    }));
+}
+
+#[test]
+fn test_fine_tuning_job_list_request_query_string() {
+    let request = FineTuningJobListRequest::new(Some(FineTuningJobListParams {
+        created_by_me: Some(true),
+        page_size: Some(10),
+        status: Some(FineTuningJobStatus::Running),
+        ..Default::default()
+    }));
+
+    expect!(utils::get_query_string(&request))
+        .to_be("?created_by_me=true&page_size=10&status=RUNNING".to_string());
+}
+
+#[test]
+fn test_fine_tuning_job_response_deserialization() {
+    let job: FineTuningJobResponse = serde_json::from_value(json!({
+        "id": "7ad642c1-fc6f-4fa5-b3a3-4a8a3c0c5e4c",
+        "object": "job",
+        "model": "open-mistral-7b",
+        "status": "RUNNING",
+        "job_type": "completion",
+        "auto_start": true,
+        "hyperparameters": { "training_steps": 10, "learning_rate": 0.0001 },
+        "training_files": ["training_file_id"],
+        "created_at": 1716963433,
+        "modified_at": 1716963501,
+        "fine_tuned_model": null,
+        "suffix": null,
+        "trained_tokens": 12345,
+        "events": [
+            {
+                "name": "status-updated",
+                "data": { "status": "RUNNING" },
+                "created_at": 1716963501
+            }
+        ],
+        "checkpoints": [
+            {
+                "metrics": { "train_loss": 0.8, "valid_loss": null, "valid_mean_token_accuracy": null },
+                "step_number": 5,
+                "created_at": 1716963600
+            }
+        ]
+    }))
+    .unwrap();
+
+    expect!(job.status.clone()).to_be(FineTuningJobStatus::Running);
+    expect!(job.hyperparameters.training_steps).to_be(Some(10));
+    expect!(job.validation_files.is_empty()).to_be(true);
+    expect!(job.events[0].name.clone()).to_be("status-updated".to_string());
+    expect!(job.checkpoints[0].step_number).to_be(5);
+    assert_eq!(job.checkpoints[0].metrics.train_loss, Some(0.8));
+    expect!(job.job_type.clone()).to_be(Some(FineTuningJobType::Completion));
+    expect!(job.is_settled()).to_be(false);
+}
+
+#[test]
+fn test_fine_tuning_job_status() {
+    let statuses: Vec<FineTuningJobStatus> = serde_json::from_value(json!([
+        "FAILED_VALIDATION",
+        "CANCELLATION_REQUESTED",
+        "PAUSED"
+    ]))
+    .unwrap();
+
+    expect!(statuses).to_be(vec![
+        FineTuningJobStatus::FailedValidation,
+        FineTuningJobStatus::CancellationRequested,
+        FineTuningJobStatus::Unknown("PAUSED".to_string()),
+    ]);
+
+    expect!(FineTuningJobStatus::Success.is_terminal()).to_be(true);
+    expect!(FineTuningJobStatus::Cancelled.is_terminal()).to_be(true);
+    expect!(FineTuningJobStatus::CancellationRequested.is_terminal()).to_be(false);
+    expect!(FineTuningJobStatus::Validated.is_terminal()).to_be(false);
+}
+
+fn get_job_json(status: &str) -> serde_json::Value {
+    json!({
+        "id": "job_id",
+        "object": "job",
+        "model": "open-mistral-7b",
+        "status": status,
+        "auto_start": true,
+        "hyperparameters": { "training_steps": 10 },
+        "training_files": ["training_file_id"],
+        "created_at": 1716963433,
+        "modified_at": 1716963501
+    })
+}
+
+#[tokio::test]
+async fn test_client_watch_fine_tuning_job_with_retryable_errors() {
+    let (url, requests) = local_api::start_local_api(vec![
+        (503, json!({ "message": "Service unavailable." })),
+        (200, get_job_json("RUNNING")),
+        (200, get_job_json("RUNNING")),
+        (200, get_job_json("SUCCESS")),
+    ]);
+    let client = Client::new(Some("api_key".to_string()), Some(url), None, None).unwrap();
+    let options = FineTuningJobWatchParams {
+        initial_interval: Duration::from_millis(1),
+        max_interval: Duration::from_millis(10),
+        // Invalid multipliers are treated as `1.0` instead of panicking.
+        multiplier: -1.0,
+    };
+
+    let statuses: Vec<FineTuningJobStatus> = client
+        .watch_fine_tuning_job("job_id", Some(options))
+        .map(|job| job.unwrap().status)
+        .collect()
+        .await;
+
+    expect!(statuses).to_be(vec![
+        FineTuningJobStatus::Running,
+        FineTuningJobStatus::Success,
+    ]);
+    expect!(requests.lock().unwrap().len()).to_be(4);
+}
+
+#[test]
+fn test_api_error_is_retryable() {
+    let error = |kind: ApiErrorKind| ApiError {
+        message: "error".to_string(),
+        kind,
+    };
+
+    expect!(error(ApiErrorKind::Status(503)).status_code()).to_be(Some(503));
+    expect!(error(ApiErrorKind::Status(503)).is_retryable()).to_be(true);
+    expect!(error(ApiErrorKind::Status(429)).is_retryable()).to_be(true);
+    expect!(error(ApiErrorKind::Status(404)).is_retryable()).to_be(false);
+    expect!(error(ApiErrorKind::Transport).status_code()).to_be(None);
+    expect!(error(ApiErrorKind::Transport).is_retryable()).to_be(true);
+    expect!(error(ApiErrorKind::Serialization).is_retryable()).to_be(false);
+    expect!(error(ApiErrorKind::Other).is_retryable()).to_be(false);
+}
+
+#[tokio::test]
+async fn test_client_watch_fine_tuning_job_with_invalid_response() {
+    let (url, requests) =
+        local_api::start_local_api(vec![(200, json!({ "id": "job_id", "status": 42 }))]);
+    let client = Client::new(Some("api_key".to_string()), Some(url), None, None).unwrap();
+    let options = FineTuningJobWatchParams {
+        initial_interval: Duration::from_millis(1),
+        ..Default::default()
+    };
+
+    let errors: Vec<ApiError> = client
+        .watch_fine_tuning_job("job_id", Some(options))
+        .map(|job| job.unwrap_err())
+        .collect()
+        .await;
+
+    // A response that can't be deserialized isn't polled again.
+    expect!(errors.len()).to_be(1);
+    expect!(errors[0].kind).to_be(ApiErrorKind::Serialization);
+    expect!(requests.lock().unwrap().len()).to_be(1);
+}
@@ -0,0 +1,177 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::{ChatMessage, ChatMessageContentChunk, ChatParams, ChatRequest},
+    client::Client,
+    constants::Model,
+    error::ModelCapabilityError,
+    model_list::ModelListResponse,
+    model_registry::{ModelFeature, ModelInfo, ModelRegistry},
+    tool::{Tool, ToolFunctionParameter, ToolFunctionParameterType},
+};
+use serde_json::json;
+use std::time::Duration;
+
+fn get_tools() -> Vec<Tool> {
+    vec![Tool::new(
+        "get_city_temperature".to_string(),
+        "Get the current temperature in a city.".to_string(),
+        vec![ToolFunctionParameter::new(
+            "city".to_string(),
+            "The name of the city.".to_string(),
+            ToolFunctionParameterType::String,
+        )],
+    )]
+}
+
+#[test]
+fn test_model_registry_from_model_list() {
+    let model_list: ModelListResponse = serde_json::from_value(json!({
+        "object": "list",
+        "data": [{
+            "id": "mistral-large-2411",
+            "object": "model",
+            "created": 1718000000,
+            "owned_by": "mistralai",
+            "permission": [],
+            "capabilities": {
+                "completion_chat": true,
+                "completion_fim": false,
+                "function_calling": true,
+                "fine_tuning": true,
+                "vision": false,
+                "classification": false,
+                "ocr": false
+            },
+            "max_context_length": 131072,
+            "aliases": ["mistral-large-latest"],
+            "deprecation": null
+        }]
+    }))
+    .unwrap();
+    let registry = ModelRegistry::from_model_list(&model_list);
+
+    let model = registry.get("mistral-large-latest").unwrap();
+    expect!(model.id.clone()).to_be("mistral-large-2411".to_string());
+    expect!(model.max_context_length).to_be(Some(131072));
+    expect!(model.supports(ModelFeature::FunctionCalling)).to_be(true);
+    expect!(model.supports(ModelFeature::Vision)).to_be(false);
+    expect!(model.capabilities.extra["ocr"].clone()).to_be(json!(false));
+    expect!(registry.get("mistral-small-latest").is_none()).to_be(true);
+}
+
+#[test]
+fn test_model_registry_check_chat_request() {
+    let registry = ModelRegistry::default();
+    let options = ChatParams {
+        tools: Some(get_tools()),
+        ..Default::default()
+    };
+    let messages = vec![ChatMessage::new_user_message("Hello!")];
+
+    let request = ChatRequest::new(
+        Model::OpenMistral7b,
+        messages.clone(),
+        false,
+        Some(options.clone()),
+    );
+    assert_eq!(
+        registry.check_chat_request(&request),
+        Err(ModelCapabilityError::UnsupportedFeature {
+            model: "open-mistral-7b".to_string(),
+            feature: ModelFeature::FunctionCalling,
+        },)
+    );
+
+    let request = ChatRequest::new(
+        Model::MistralLargeLatest,
+        messages.clone(),
+        false,
+        Some(options.clone()),
+    );
+    assert_eq!(registry.check_chat_request(&request), Ok(()));
+
+    let request = ChatRequest::new(
+        Model::new("ft:open-mistral-7b:587a6b29:20240514:7e773925"),
+        messages,
+        false,
+        Some(options),
+    );
+    assert_eq!(registry.check_chat_request(&request), Ok(()));
+
+    let image_messages = vec![ChatMessage::new_user_message(vec![
+        ChatMessageContentChunk::new_image_url("https://example.org/cat.png"),
+    ])];
+    let request = ChatRequest::new(Model::CodestralLatest, image_messages, false, None);
+    assert_eq!(
+        registry.check_chat_request(&request),
+        Err(ModelCapabilityError::UnsupportedFeature {
+            model: "codestral-latest".to_string(),
+            feature: ModelFeature::Vision,
+        },)
+    );
+}
+
+#[test]
+fn test_client_chat_with_model_registry() {
+    let mut client = Client::new(Some("api_key".to_string()), None, None, None).unwrap();
+    client.set_model_registry(Some(ModelRegistry::default()));
+
+    let messages = vec![ChatMessage::new_user_message("Hello!")];
+    let options = ChatParams {
+        tools: Some(get_tools()),
+        ..Default::default()
+    };
+
+    match client.chat(Model::OpenMistral7b, messages, Some(options)) {
+        Ok(_) => panic!("Expected an `ApiError` but got Ok."),
+        Err(error) => expect!(error.message)
+            .to_be("The `open-mistral-7b` model doesn't support function calling.".to_string()),
+    }
+}
+
+#[test]
+fn test_model_registry_resolve() {
+    let model_list: ModelListResponse = serde_json::from_value(json!({
+        "object": "list",
+        "data": [
+            {
+                "id": "mistral-large-latest",
+                "object": "model",
+                "created": 1718000000,
+                "owned_by": "mistralai",
+                "aliases": ["mistral-large-2411"]
+            },
+            {
+                "id": "mistral-large-2411",
+                "object": "model",
+                "created": 1718000000,
+                "owned_by": "mistralai",
+                "aliases": ["mistral-large-latest"]
+            }
+        ]
+    }))
+    .unwrap();
+    let registry = ModelRegistry::from_model_list(&model_list);
+
+    expect!(registry.resolve("mistral-large-latest")).to_be("mistral-large-2411".to_string());
+    expect!(registry.resolve("mistral-large-2411")).to_be("mistral-large-2411".to_string());
+    expect!(registry.resolve("mistral-small-latest")).to_be("mistral-small-latest".to_string());
+}
+
+#[test]
+fn test_model_info_is_deprecated_within() {
+    let model = |deprecation: Option<&str>| ModelInfo {
+        id: "open-mistral-7b".to_string(),
+        aliases: Vec::new(),
+        capabilities: Default::default(),
+        max_context_length: None,
+        deprecation: deprecation.map(|date| date.to_string()),
+        deprecation_replacement_model: None,
+    };
+    let period = Duration::from_secs(30 * 24 * 60 * 60);
+
+    expect!(model(Some("2024-11-30T12:00:00Z")).is_deprecated_within(period)).to_be(true);
+    expect!(model(Some("2999-01-01T00:00:00Z")).is_deprecated_within(period)).to_be(false);
+    expect!(model(Some("unknown")).is_deprecated_within(period)).to_be(false);
+    expect!(model(None).is_deprecated_within(period)).to_be(false);
+}
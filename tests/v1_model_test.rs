@@ -0,0 +1,50 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    model::{ModelUpdateParams, ModelUpdateRequest},
+    model_list::{ModelListData, ModelType},
+};
+use serde_json::json;
+
+#[test]
+fn test_model_update_request_serialization() {
+    let request = ModelUpdateRequest::new(ModelUpdateParams {
+        name: Some("Support Bot".to_string()),
+        ..Default::default()
+    });
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({ "name": "Support Bot" }));
+}
+
+#[test]
+fn test_fine_tuned_model_deserialization() {
+    let model: ModelListData = serde_json::from_value(json!({
+        "id": "ft:open-mistral-7b:587a6b29:20240514:7e773925",
+        "object": "model",
+        "created": 1715700000,
+        "owned_by": "587a6b29",
+        "type": "fine-tuned",
+        "name": "Support Bot",
+        "description": null,
+        "capabilities": {
+            "completion_chat": true,
+            "completion_fim": false,
+            "function_calling": false,
+            "fine_tuning": false,
+            "vision": false,
+            "classification": false
+        },
+        "max_context_length": 32768,
+        "aliases": [],
+        "job": "2813f4ee-8a30-4bdf-a6c4-1ec7f6f5c7ad",
+        "root": "open-mistral-7b",
+        "archived": false
+    }))
+    .unwrap();
+
+    expect!(model.r#type).to_be(Some(ModelType::FineTuned));
+    expect!(model.name).to_be(Some("Support Bot".to_string()));
+    expect!(model.job).to_be(Some("2813f4ee-8a30-4bdf-a6c4-1ec7f6f5c7ad".to_string()));
+    expect!(model.root).to_be(Some("open-mistral-7b".to_string()));
+    expect!(model.archived).to_be(Some(false));
+    expect!(model.permission.len()).to_be(0);
+}
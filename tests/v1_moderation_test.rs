@@ -0,0 +1,105 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::ChatMessage,
+    constants::Model,
+    error::ModerationError,
+    moderation::{ChatModerationRequest, ModerationCategory, ModerationResponse},
+};
+use serde_json::json;
+use std::collections::HashMap;
+
+fn get_moderation_response() -> ModerationResponse {
+    serde_json::from_value(json!({
+        "id": "4d3c7b8c2f1e4a5b9c6d7e8f9a0b1c2d",
+        "model": "mistral-moderation-latest",
+        "results": [
+            {
+                "categories": {
+                    "sexual": false,
+                    "hate_and_discrimination": false,
+                    "violence_and_threats": true,
+                    "dangerous_and_criminal_content": false,
+                    "selfharm": false,
+                    "health": false,
+                    "financial": false,
+                    "law": false,
+                    "pii": false,
+                    "jailbreaking": false
+                },
+                "category_scores": {
+                    "sexual": 0.0001,
+                    "hate_and_discrimination": 0.002,
+                    "violence_and_threats": 0.91,
+                    "dangerous_and_criminal_content": 0.42,
+                    "selfharm": 0.0003,
+                    "health": 0.001,
+                    "financial": 0.0002,
+                    "law": 0.003,
+                    "pii": 0.0001,
+                    "jailbreaking": 0.02
+                }
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_chat_moderation_request_serialization() {
+    let request = ChatModerationRequest::new(
+        Model::MistralModerationLatest,
+        vec![vec![
+            ChatMessage::new_user_message("Hi!"),
+            ChatMessage::new_assistant_message("Hello!", None),
+        ]],
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "mistral-moderation-latest",
+        "input": [[
+            { "role": "user", "content": "Hi!" },
+            { "role": "assistant", "content": "Hello!" }
+        ]]
+    }));
+}
+
+#[test]
+fn test_moderation_response_deserialization() {
+    let response = get_moderation_response();
+    let result = &response.results[0];
+
+    expect!(result.is_flagged()).to_be(true);
+    expect!(result.categories[&ModerationCategory::ViolenceAndThreats]).to_be(true);
+    expect!(result.categories[&ModerationCategory::Unknown("jailbreaking".to_string())])
+        .to_be(false);
+    assert_eq!(
+        result.category_scores[&ModerationCategory::DangerousAndCriminalContent],
+        0.42
+    );
+}
+
+#[test]
+fn test_moderation_flagged_categories() {
+    let response = get_moderation_response();
+
+    expect!(response.get_flagged_categories(&HashMap::new()))
+        .to_be(vec![ModerationCategory::ViolenceAndThreats]);
+
+    let thresholds = HashMap::from([
+        (ModerationCategory::DangerousAndCriminalContent, 0.4),
+        (ModerationCategory::ViolenceAndThreats, 0.95),
+    ]);
+    expect!(response.get_flagged_categories(&thresholds))
+        .to_be(vec![ModerationCategory::DangerousAndCriminalContent]);
+
+    let error = ModerationError::InputBlocked {
+        categories: vec![
+            ModerationCategory::DangerousAndCriminalContent,
+            ModerationCategory::ViolenceAndThreats,
+        ],
+    };
+    expect!(error.to_string()).to_be(
+        "The user input is flagged by the moderation: dangerous_and_criminal_content, violence_and_threats."
+            .to_string(),
+    );
+}
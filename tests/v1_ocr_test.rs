@@ -0,0 +1,145 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    chat::ResponseFormat,
+    constants::Model,
+    error::OcrImageError,
+    ocr::{OcrDocument, OcrPageDimensions, OcrParams, OcrRequest, OcrResponse},
+};
+use serde::Deserialize;
+use serde_json::json;
+
+#[test]
+fn test_ocr_request_serialization() {
+    let request = OcrRequest::new(
+        Model::MistralOcrLatest,
+        OcrDocument::new_document_url("https://example.com/document.pdf"),
+        None,
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "mistral-ocr-latest",
+        "document": { "type": "document_url", "document_url": "https://example.com/document.pdf" }
+    }));
+
+    let request = OcrRequest::new(
+        Model::MistralOcrLatest,
+        OcrDocument::new_file("file_id"),
+        Some(OcrParams {
+            document_annotation_format: Some(ResponseFormat::json_object()),
+            image_limit: Some(2),
+            include_image_base64: Some(true),
+            pages: Some(vec![0, 2]),
+            ..Default::default()
+        }),
+    );
+
+    expect!(serde_json::to_value(&request).unwrap()).to_be(json!({
+        "model": "mistral-ocr-latest",
+        "document": { "type": "file", "file_id": "file_id" },
+        "document_annotation_format": { "type": "json_object" },
+        "image_limit": 2,
+        "include_image_base64": true,
+        "pages": [0, 2]
+    }));
+
+    let document = OcrDocument::new_image_url("data:image/png;base64,iVBORw0KGgo=");
+    expect!(serde_json::to_value(&document).unwrap()).to_be(json!({
+        "type": "image_url",
+        "image_url": "data:image/png;base64,iVBORw0KGgo="
+    }));
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+struct Invoice {
+    number: String,
+}
+
+#[test]
+fn test_ocr_response_deserialization() {
+    let response: OcrResponse = serde_json::from_value(json!({
+        "model": "mistral-ocr-2505",
+        "pages": [
+            {
+                "index": 0,
+                "markdown": "# Invoice\n\n![img-0.jpeg](img-0.jpeg)",
+                "images": [
+                    {
+                        "id": "img-0.jpeg",
+                        "top_left_x": 10,
+                        "top_left_y": 20,
+                        "bottom_right_x": 110,
+                        "bottom_right_y": 220,
+                        "image_base64": "data:image/jpeg;base64,SGVsbG8=",
+                        "image_annotation": null
+                    }
+                ],
+                "dimensions": { "dpi": 200, "height": 2200, "width": 1700 }
+            },
+            {
+                "index": 1,
+                "markdown": "Total: 42",
+                "images": [],
+                "dimensions": null
+            }
+        ],
+        "document_annotation": "{\"number\": \"INV-001\"}",
+        "usage_info": { "pages_processed": 2, "doc_size_bytes": 3456 }
+    }))
+    .unwrap();
+
+    expect!(response.get_markdown())
+        .to_be("# Invoice\n\n![img-0.jpeg](img-0.jpeg)\n\nTotal: 42".to_string());
+    expect!(response.pages[0].dimensions.clone()).to_be(Some(OcrPageDimensions {
+        dpi: 200,
+        height: 2200,
+        width: 1700,
+    }));
+    expect!(response.pages[0].images[0].bottom_right_y).to_be(Some(220));
+    expect!(response.usage_info.pages_processed).to_be(2);
+    expect!(response
+        .parse_document_annotation::<Invoice>()
+        .unwrap()
+        .unwrap())
+    .to_be(Invoice {
+        number: "INV-001".to_string(),
+    });
+    expect!(response.pages[0].images[0]
+        .parse_annotation::<Invoice>()
+        .is_none())
+    .to_be(true);
+}
+
+#[test]
+fn test_ocr_image_decoding() {
+    let response: OcrResponse = serde_json::from_value(json!({
+        "model": "mistral-ocr-latest",
+        "pages": [{
+            "index": 0,
+            "markdown": "",
+            "images": [
+                { "id": "img-0.jpeg", "image_base64": "data:image/jpeg;base64,SGVsbG8=" },
+                { "id": "img-1.png", "image_base64": "SGk=" }
+            ]
+        }],
+        "usage_info": { "pages_processed": 1 }
+    }))
+    .unwrap();
+    let images = &response.pages[0].images;
+
+    expect!(images[0].decode().unwrap()).to_be(b"Hello".to_vec());
+    expect!(images[1].decode().unwrap()).to_be(b"Hi".to_vec());
+
+    let directory = std::env::temp_dir().join("mistralai_client_ocr_test");
+    let paths = response.save_images(&directory).unwrap();
+    expect!(paths.clone()).to_be(vec![
+        directory.join("img-0.jpeg"),
+        directory.join("img-1.png"),
+    ]);
+    expect!(std::fs::read(&paths[0]).unwrap()).to_be(b"Hello".to_vec());
+    std::fs::remove_dir_all(&directory).unwrap();
+
+    let mut image = images[0].clone();
+    image.image_base64 = None;
+    expect!(image.decode().unwrap_err())
+        .to_be(OcrImageError::MissingData("img-0.jpeg".to_string()));
+}
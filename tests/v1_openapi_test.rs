@@ -0,0 +1,260 @@
+use jrest::expect;
+use mistralai_client::v1::{
+    error::OpenApiError,
+    openapi::{
+        OpenApiParameterLocation, OpenApiSkippedOperation, OpenApiTools, OpenApiToolsParams,
+    },
+};
+use serde_json::json;
+
+mod local_api;
+
+const DOCUMENT: &str = r##"
+openapi: 3.0.3
+info:
+  title: Weather API
+  version: 1.0.0
+servers:
+  - url: https://weather.example.org/v1/
+paths:
+  /cities/{cityId}/temperature:
+    parameters:
+      - $ref: "#/components/parameters/CityId"
+    get:
+      operationId: getCityTemperature
+      summary: Get the current temperature in a city.
+      tags: [weather]
+      parameters:
+        - name: unit
+          in: query
+          description: The temperature unit.
+          schema:
+            type: string
+            enum: [celsius, fahrenheit]
+  /alerts:
+    post:
+      operationId: createAlert
+      tags: [alerts]
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              $ref: "#/components/schemas/Alert"
+components:
+  parameters:
+    CityId:
+      name: cityId
+      in: path
+      required: true
+      description: The city identifier.
+      schema:
+        type: string
+  schemas:
+    Alert:
+      type: object
+      properties:
+        message:
+          type: string
+      required: [message]
+"##;
+
+#[test]
+fn test_openapi_tools_from_document() {
+    let openapi_tools =
+        OpenApiTools::from_document(DOCUMENT, OpenApiToolsParams::default()).unwrap();
+
+    expect!(openapi_tools.operations.len()).to_be(2);
+
+    let get_temperature = openapi_tools
+        .operations
+        .iter()
+        .find(|operation| operation.function.name == "getCityTemperature")
+        .unwrap();
+    expect!(get_temperature.function.url.clone())
+        .to_be("https://weather.example.org/v1/cities/{cityId}/temperature".to_string());
+    expect!(get_temperature.function.method.clone()).to_be(reqwest::Method::GET);
+    expect!(get_temperature.function.has_body).to_be(false);
+    expect!(get_temperature.function.parameters[0].location.clone())
+        .to_be(OpenApiParameterLocation::Path);
+    expect!(serde_json::to_value(&get_temperature.tool).unwrap()).to_be(json!({
+        "type": "function",
+        "function": {
+            "name": "getCityTemperature",
+            "description": "Get the current temperature in a city.",
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    "cityId": {
+                        "type": "string",
+                        "description": "The city identifier."
+                    },
+                    "unit": {
+                        "type": "string",
+                        "enum": ["celsius", "fahrenheit"],
+                        "description": "The temperature unit."
+                    }
+                },
+                "required": ["cityId"]
+            }
+        }
+    }));
+
+    let create_alert = openapi_tools
+        .operations
+        .iter()
+        .find(|operation| operation.function.name == "createAlert")
+        .unwrap();
+    expect!(create_alert.function.has_body).to_be(true);
+    let create_alert_tool = serde_json::to_value(&create_alert.tool).unwrap();
+    expect!(create_alert_tool["function"]["parameters"].clone()).to_be(json!({
+        "type": "object",
+        "properties": {
+            "body": {
+                "type": "object",
+                "properties": { "message": { "type": "string" } },
+                "required": ["message"]
+            }
+        },
+        "required": ["body"]
+    }));
+}
+
+#[test]
+fn test_openapi_tools_from_document_with_filters() {
+    let params = OpenApiToolsParams {
+        base_url: Some("http://localhost:8080".to_string()),
+        tags: Some(vec!["alerts".to_string()]),
+        ..Default::default()
+    };
+    let openapi_tools = OpenApiTools::from_document(DOCUMENT, params).unwrap();
+
+    expect!(openapi_tools.operations.len()).to_be(1);
+    expect!(openapi_tools.operations[0].function.url.clone())
+        .to_be("http://localhost:8080/alerts".to_string());
+
+    let params = OpenApiToolsParams {
+        operation_ids: Some(vec!["getCityTemperature".to_string()]),
+        ..Default::default()
+    };
+    let openapi_tools = OpenApiTools::from_document(DOCUMENT, params).unwrap();
+
+    expect!(openapi_tools.operations.len()).to_be(1);
+    expect!(openapi_tools.operations[0].function.name.clone())
+        .to_be("getCityTemperature".to_string());
+}
+
+#[test]
+fn test_openapi_tools_from_document_without_base_url() {
+    let document = r#"{ "openapi": "3.1.0", "paths": {} }"#;
+
+    match OpenApiTools::from_document(document, OpenApiToolsParams::default()) {
+        Ok(_) => panic!("Expected `OpenApiError::MissingBaseUrl` but got Ok."),
+        Err(error) => assert_eq!(error, OpenApiError::MissingBaseUrl),
+    }
+}
+
+#[test]
+fn test_openapi_tools_from_document_with_conflicting_and_skipped_operations() {
+    let document = json!({
+        "openapi": "3.0.3",
+        "servers": [{ "url": "https://example.org" }],
+        "paths": {
+            "/users": {
+                "get": { "operationId": "list.users" },
+                "post": { "operationId": "list users" },
+                "put": {
+                    "operationId": "uploadUsers",
+                    "requestBody": { "content": { "text/csv": { "schema": { "type": "string" } } } }
+                }
+            }
+        }
+    })
+    .to_string();
+    let openapi_tools =
+        OpenApiTools::from_document(&document, OpenApiToolsParams::default()).unwrap();
+
+    let names: Vec<String> = openapi_tools
+        .operations
+        .iter()
+        .map(|operation| operation.function.name.clone())
+        .collect();
+    expect!(names).to_be(vec!["list_users".to_string(), "list_users_2".to_string()]);
+    let tool = serde_json::to_value(&openapi_tools.operations[1].tool).unwrap();
+    expect!(tool["function"]["name"].clone()).to_be(json!("list_users_2"));
+
+    expect!(openapi_tools.skipped_operations).to_be(vec![OpenApiSkippedOperation {
+        method: "PUT".to_string(),
+        path: "/users".to_string(),
+        reason: "only JSON request bodies are supported, not `text/csv`.".to_string(),
+    }]);
+}
+
+#[tokio::test]
+async fn test_openapi_tools_from_document_with_conflicting_parameters() {
+    let (url, requests, bodies) =
+        local_api::start_local_api_with_bodies(vec![(200, json!({ "ok": true }))]);
+    let document = json!({
+        "openapi": "3.1.0",
+        "servers": [{ "url": url }],
+        "paths": {
+            "/items/{id}": {
+                "post": {
+                    "operationId": "updateItem",
+                    "parameters": [
+                        { "name": "id", "in": "path", "required": true },
+                        { "name": "id", "in": "query" },
+                        { "name": "id", "in": "header" },
+                        { "name": "body", "in": "query" }
+                    ],
+                    "requestBody": {
+                        "content": { "application/json": { "schema": { "type": "object" } } }
+                    }
+                }
+            }
+        }
+    })
+    .to_string();
+    let openapi_tools =
+        OpenApiTools::from_document(&document, OpenApiToolsParams::default()).unwrap();
+    let operation = &openapi_tools.operations[0];
+
+    let arguments: Vec<String> = operation
+        .function
+        .parameters
+        .iter()
+        .map(|parameter| parameter.argument.clone())
+        .collect();
+    expect!(arguments).to_be(vec![
+        "id".to_string(),
+        "query_id".to_string(),
+        "header_id".to_string(),
+        "query_body".to_string(),
+    ]);
+    let tool = serde_json::to_value(&operation.tool).unwrap();
+    let mut properties: Vec<String> = tool["function"]["parameters"]["properties"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .cloned()
+        .collect();
+    properties.sort();
+    expect!(properties).to_be(vec![
+        "body".to_string(),
+        "header_id".to_string(),
+        "id".to_string(),
+        "query_body".to_string(),
+        "query_id".to_string(),
+    ]);
+
+    let response = operation
+        .function
+        .call(r#"{"id": "1", "query_id": "2", "query_body": "3", "body": {"name": "Paris"}}"#)
+        .await
+        .unwrap();
+
+    expect!(response).to_be(r#"{"ok":true}"#.to_string());
+    expect!(requests.lock().unwrap().clone())
+        .to_be(vec!["POST /items/1?id=2&body=3 ()".to_string()]);
+    expect!(bodies.lock().unwrap().clone()).to_be(vec![json!({ "name": "Paris" })]);
+}
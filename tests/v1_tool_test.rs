@@ -1,7 +1,32 @@
-use mistralai_client::v1::client::Client;
+use jrest::expect;
+use mistralai_client::v1::{client::Client, tool::ToolChoice};
+use serde_json::json;
 
 trait _Trait: Send {}
 struct _Foo {
     _dummy: Client,
 }
 impl _Trait for _Foo {}
+
+#[test]
+fn test_tool_choice_serialization() {
+    expect!(serde_json::to_value(ToolChoice::Auto).unwrap()).to_be(json!("auto"));
+    expect!(serde_json::to_value(ToolChoice::Required).unwrap()).to_be(json!("required"));
+    expect!(serde_json::to_value(ToolChoice::new_function("get_city_temperature")).unwrap()).to_be(
+        json!({
+            "type": "function",
+            "function": { "name": "get_city_temperature" }
+        }),
+    );
+}
+
+#[test]
+fn test_tool_choice_deserialization() {
+    expect!(serde_json::from_value::<ToolChoice>(json!("any")).unwrap()).to_be(ToolChoice::Any);
+    expect!(serde_json::from_value::<ToolChoice>(json!({
+        "type": "function",
+        "function": { "name": "get_city_temperature" }
+    }))
+    .unwrap())
+    .to_be(ToolChoice::new_function("get_city_temperature"));
+}